@@ -7,6 +7,7 @@ pub struct AbilityData {
     pub movement_cost: u32,
     pub cast_time: u32,
     pub pattern: Pattern,
+    pub autocast: bool, // casts itself when the piece arrives, no player action
 }
 
 impl AbilityData {
@@ -23,8 +24,21 @@ impl AbilityData {
             movement_cost,
             cast_time,
             pattern,
+            autocast: false,
         }
     }
+
+    pub fn with_autocast(mut self) -> Self {
+        self.autocast = true;
+        self
+    }
+}
+
+/// An ability waiting for its cast time to elapse
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cast {
+    pub target: AbilityTarget,
+    pub ready_at: ChessTime,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -55,3 +69,363 @@ pub enum AbilityType {
 }
 
 // TODO the rest
+
+/// What a pawn can become at the end of the board
+pub const PROMOTIONS: [PieceType; 4] = [
+    PieceType::Queen,
+    PieceType::Rook,
+    PieceType::Bishop,
+    PieceType::Knight,
+];
+
+impl PieceType {
+    /// Pieces whose ability the board knows how to resolve
+    pub fn has_ability(&self) -> bool {
+        matches!(
+            self,
+            PieceType::Pawn
+                | PieceType::Builder
+                | PieceType::Catapult
+                | PieceType::Dragon
+                | PieceType::Necromancer
+                | PieceType::Ogre
+                | PieceType::Oni
+                | PieceType::Ram
+                | PieceType::Spider
+                | PieceType::Succubus
+                | PieceType::Warlock
+                | PieceType::Portal
+                | PieceType::Imp
+                | PieceType::Mandragora
+                | PieceType::CrazyPawn
+                | PieceType::ShieldBearer
+                | PieceType::SuperPawn
+                | PieceType::Ship
+                | PieceType::Mermaid
+                | PieceType::Witch
+                | PieceType::Leech
+                | PieceType::TeslaTower
+                | PieceType::Basilisk
+                | PieceType::SpiderEgg
+                | PieceType::Wall
+        )
+    }
+}
+
+impl Board {
+    /// Targets worth trying for the ability of the piece, used to generate the legal actions
+    pub fn ability_targets(&self, pos: Position, piece: &Piece) -> Vec<AbilityTarget> {
+        match piece.piece_type {
+            PieceType::Pawn => PROMOTIONS.into_iter().map(AbilityTarget::Promote).collect(),
+            PieceType::Catapult => self.throw_targets(pos),
+            PieceType::Necromancer => self.enemy_targets(piece),
+            PieceType::Succubus => piece
+                .piece_type
+                .get_area_ability(self.forward(piece.color))
+                .map(|area| self.area_targets(pos, piece, &area))
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(target, _)| AbilityTarget::Position(target))
+                .collect(),
+            PieceType::Ogre => self.push_targets(pos),
+            PieceType::Imp => self.imp_targets(piece),
+            PieceType::Spider => vec![
+                AbilityTarget::Direction(Direction::Up),
+                AbilityTarget::Direction(Direction::Down),
+            ],
+            PieceType::Builder | PieceType::Dragon | PieceType::Ram => Direction::all()
+                .into_iter()
+                .map(AbilityTarget::Direction)
+                .collect(),
+            PieceType::Oni | PieceType::Warlock => SubDirection::all()
+                .into_iter()
+                .map(AbilityTarget::SubDirection)
+                .collect(),
+            PieceType::Portal => PORTAL_UNITS
+                .into_iter()
+                .flat_map(|unit| {
+                    SubDirection::all()
+                        .into_iter()
+                        .map(move |subdirection| AbilityTarget::Summon(unit, subdirection))
+                })
+                .collect(),
+            piece_type if piece_type.has_ability() => vec![AbilityTarget::None],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Checks the target of the ability, without touching the board
+    pub fn check_ability_target(
+        &self,
+        pos: Position,
+        piece: &Piece,
+        target: &AbilityTarget,
+    ) -> Result<(), RuleError> {
+        match (piece.piece_type, target) {
+            (PieceType::Pawn, AbilityTarget::Promote(piece_type)) => {
+                if PROMOTIONS.contains(piece_type) {
+                    Ok(())
+                } else {
+                    Err(RuleError::InvalidTarget)
+                }
+            }
+            (PieceType::Builder, AbilityTarget::Direction(direction)) => {
+                self.check_build_walls(pos, *direction)
+            }
+            (PieceType::Catapult, AbilityTarget::Throw(throw)) => self.check_throw(pos, throw),
+            (PieceType::Necromancer, AbilityTarget::Position(target)) => {
+                self.check_raise_dead(piece, *target)
+            }
+            (PieceType::Ogre, AbilityTarget::Push(push)) => self.check_push(pos, push),
+            (PieceType::Imp, _) => self.check_imp_draw(piece, target),
+            (PieceType::Oni, AbilityTarget::SubDirection(subdirection)) => {
+                self.check_oni_teleport(piece, *subdirection).map(|_| ())
+            }
+            (PieceType::Dragon | PieceType::Ram, AbilityTarget::Direction(_)) => Ok(()),
+            (PieceType::Spider, AbilityTarget::Direction(direction)) => {
+                self.check_lay_egg(pos, *direction)
+            }
+            (PieceType::Succubus, AbilityTarget::Position(target)) => {
+                let area = piece
+                    .piece_type
+                    .get_area_ability(self.forward(piece.color))
+                    .ok_or(RuleError::NotExecutable)?;
+                self.check_area_target(pos, piece, &area, Some(*target))
+            }
+            (PieceType::Warlock, AbilityTarget::SubDirection(subdirection)) => {
+                self.check_build_portal(pos + *subdirection)
+            }
+            (PieceType::Portal, AbilityTarget::Summon(unit, subdirection)) => {
+                self.check_portal_summon(pos, piece, *unit, *subdirection)
+            }
+            (
+                PieceType::Mandragora
+                | PieceType::CrazyPawn
+                | PieceType::ShieldBearer
+                | PieceType::SuperPawn
+                | PieceType::Ship
+                | PieceType::Mermaid
+                | PieceType::Witch
+                | PieceType::Leech
+                | PieceType::TeslaTower
+                | PieceType::Basilisk
+                | PieceType::SpiderEgg
+                | PieceType::Wall,
+                AbilityTarget::None,
+            ) => Ok(()),
+            _ if piece.piece_type.has_ability() => Err(RuleError::InvalidTarget),
+            // nothing resolves it, so it cannot be used
+            _ => Err(RuleError::NotExecutable),
+        }
+    }
+
+    /// Does what the ability does, the costs were already paid
+    pub fn resolve_ability(
+        &mut self,
+        piece_id: u32,
+        target: &AbilityTarget,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        let (pos, piece) = self.find_piece(piece_id).ok_or(RuleError::NoPiece)?;
+        let piece = piece.clone();
+        self.check_ability_target(pos, &piece, target)?;
+        match (piece.piece_type, target) {
+            (PieceType::Pawn, AbilityTarget::Promote(piece_type)) => {
+                self.transform_piece(piece.id, *piece_type)?;
+                outcome.transformed.push((piece.id, *piece_type));
+                Ok(())
+            }
+            (PieceType::Builder, AbilityTarget::Direction(direction)) => {
+                self.build_walls(pos, &piece, *direction, outcome)
+            }
+            (PieceType::Catapult, AbilityTarget::Throw(throw)) => {
+                self.throw_piece(pos, &piece, throw, outcome)
+            }
+            (PieceType::Necromancer, AbilityTarget::Position(target)) => {
+                self.raise_dead(&piece, *target, outcome)
+            }
+            (PieceType::Ogre, AbilityTarget::Push(push)) => {
+                self.push_piece(pos, &piece, push, outcome)
+            }
+            (PieceType::Oni, AbilityTarget::SubDirection(subdirection)) => {
+                self.oni_teleport(pos, &piece, *subdirection, outcome)
+            }
+            (PieceType::Ram, AbilityTarget::Direction(direction)) => {
+                let trace = self.ram(piece.id, *direction)?;
+                Self::record_ram(piece.id, &trace, outcome);
+                Ok(())
+            }
+            (PieceType::Spider, AbilityTarget::Direction(direction)) => {
+                self.lay_egg(pos, &piece, *direction, outcome)
+            }
+            (PieceType::Succubus, AbilityTarget::Position(target)) => {
+                let forward = self.forward(piece.color);
+                match piece.piece_type.get_area_ability(forward) {
+                    Some(area) => self.apply_area(pos, &piece, &area, Some(*target), outcome),
+                    None => Ok(()),
+                }
+            }
+            (PieceType::Dragon, AbilityTarget::Direction(direction)) => {
+                match piece.piece_type.get_area_ability(*direction) {
+                    Some(area) => self.apply_area(pos, &piece, &area, None, outcome),
+                    None => Ok(()),
+                }
+            }
+            (PieceType::Mandragora, AbilityTarget::None) => self.mandragora_mana(&piece),
+            (PieceType::Imp, _) => self.imp_draw(&piece, target, outcome),
+            (PieceType::CrazyPawn, AbilityTarget::None) => self.crazy_pawn_draw(&piece, outcome),
+            (PieceType::ShieldBearer, AbilityTarget::None) => {
+                self.shield_sides(pos, &piece, outcome);
+                Ok(())
+            }
+            (PieceType::SuperPawn, AbilityTarget::None) => {
+                self.fortify(&piece, outcome);
+                Ok(())
+            }
+            (PieceType::Ship, AbilityTarget::None) => {
+                self.broadside(pos, &piece, outcome);
+                Ok(())
+            }
+            (PieceType::Mermaid, AbilityTarget::None) => {
+                self.hasten_portals(pos, &piece, 2);
+                Ok(())
+            }
+            (PieceType::Witch | PieceType::Leech, AbilityTarget::None) => {
+                self.blast(pos, &piece, 2, outcome);
+                Ok(())
+            }
+            (PieceType::TeslaTower | PieceType::Basilisk, AbilityTarget::None) => {
+                let forward = self.forward(piece.color);
+                match piece.piece_type.get_area_ability(forward) {
+                    Some(area) => self.apply_area(pos, &piece, &area, None, outcome),
+                    None => Ok(()),
+                }
+            }
+            (PieceType::SpiderEgg, AbilityTarget::None) => {
+                self.transform_piece(piece.id, PieceType::Spider)?;
+                outcome.transformed.push((piece.id, PieceType::Spider));
+                Ok(())
+            }
+            (PieceType::Warlock, AbilityTarget::SubDirection(subdirection)) => {
+                self.build_portal(pos + *subdirection, &piece, outcome)
+            }
+            (PieceType::Portal, AbilityTarget::Summon(unit, subdirection)) => {
+                self.summon_from_portal(pos, &piece, *unit, *subdirection, outcome)
+            }
+            (PieceType::Wall, AbilityTarget::None) => self.deconstruct_wall(&piece, outcome),
+            _ => Err(RuleError::NotExecutable),
+        }
+    }
+
+    /// Starts the cast of the ability, or resolves it right away without cast time.
+    /// An instant ability that fails to resolve already gave its mana back.
+    pub(crate) fn use_ability(
+        &mut self,
+        piece_id: u32,
+        target: AbilityTarget,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        let now = self.time;
+        let piece = self.get_piece_mut(piece_id).ok_or(RuleError::NoPiece)?;
+        let cast_time = piece.ability_data.cast_time;
+        if cast_time > 0 {
+            piece.cast = Some(Cast {
+                target,
+                ready_at: now.after_rounds(cast_time),
+            });
+            Ok(())
+        } else {
+            self.finish_ability(piece_id, &target, outcome)
+        }
+    }
+
+    /// Starts the cast of an autocast ability, called when the piece arrives on the board
+    pub(crate) fn start_autocast(&mut self, piece_id: u32) {
+        let now = self.time;
+        let Some(piece) = self.get_piece_mut(piece_id) else {
+            return;
+        };
+        if piece.ability_data.autocast {
+            piece.cast = Some(Cast {
+                target: AbilityTarget::None,
+                ready_at: now.after_rounds(piece.ability_data.cast_time),
+            });
+        }
+    }
+
+    /// Every cast in progress on the board, by piece id
+    pub fn pending_casts(&self) -> Vec<(u32, &Cast)> {
+        self.board
+            .iter()
+            .flatten()
+            .filter_map(|square| square.piece.as_ref())
+            .filter_map(|piece| piece.cast.as_ref().map(|cast| (piece.id, cast)))
+            .collect()
+    }
+
+    /// Brings the cast of the piece some rounds closer, a due cast resolves on the next movement
+    pub fn hasten_cast(&mut self, piece_id: u32, rounds: u32) {
+        if let Some(cast) = self.get_piece_mut(piece_id).and_then(|p| p.cast.as_mut()) {
+            cast.ready_at = cast.ready_at.before_rounds(rounds);
+        }
+    }
+
+    pub fn delay_cast(&mut self, piece_id: u32, rounds: u32) {
+        if let Some(cast) = self.get_piece_mut(piece_id).and_then(|p| p.cast.as_mut()) {
+            cast.ready_at = cast.ready_at.after_rounds(rounds);
+        }
+    }
+
+    /// Resolves every cast whose time has come
+    pub(crate) fn complete_casts(&mut self) {
+        let now = self.time;
+        let ready: Vec<u32> = self
+            .board
+            .iter()
+            .flatten()
+            .filter_map(|square| square.piece.as_ref())
+            .filter(|piece| piece.cast.as_ref().is_some_and(|cast| cast.ready_at <= now))
+            .map(|piece| piece.id)
+            .collect();
+        for id in ready {
+            let Some(cast) = self.get_piece_mut(id).and_then(|piece| piece.cast.take()) else {
+                continue;
+            };
+            let mut outcome = Outcome::default();
+            let result = self.finish_ability(id, &cast.target, &mut outcome);
+            self.cast_outcomes.push((id, result.map(|_| outcome)));
+        }
+    }
+
+    /// Outcomes of the casts resolved since the last call, a fizzled cast keeps its error
+    pub fn take_cast_outcomes(&mut self) -> Vec<(u32, Result<Outcome, RuleError>)> {
+        std::mem::take(&mut self.cast_outcomes)
+    }
+
+    /// Resolves the ability and starts its cooldown. A fizzled ability, like a cast whose
+    /// target got blocked meanwhile, refunds its mana and stays ready.
+    fn finish_ability(
+        &mut self,
+        piece_id: u32,
+        target: &AbilityTarget,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        // the data of the ability used, a promotion or a hatch changes it
+        let (color, mana_cost, cooldown) = self
+            .get_piece(piece_id)
+            .map(|piece| {
+                let data = &piece.ability_data;
+                (piece.color, data.mana_cost, data.cooldown)
+            })
+            .ok_or(RuleError::NoPiece)?;
+        if let Err(err) = self.resolve_ability(piece_id, target, outcome) {
+            self.add_mana_to_color(color, mana_cost);
+            return Err(err);
+        }
+        let now = self.time;
+        if let Some(piece) = self.get_piece_mut(piece_id) {
+            piece.ability_ready_at = now.after_rounds(cooldown);
+        }
+        Ok(())
+    }
+}
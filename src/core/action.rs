@@ -25,6 +25,7 @@ pub enum PieceAction {
     Ability(AbilityAction),
     Die(Position),  // Position of the piece that dies
     Kill(Position), // Position of the piece that kills
+    Transform(TransformAction),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -34,9 +35,46 @@ pub struct FromToAction {
     pub piece_id: u32,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransformAction {
+    pub piece_id: u32,
+    pub from: PieceType,
+    pub to: PieceType,
+}
+
+/// A catapult throwing the adjacent piece distance squares away from the catapult
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThrowAction {
+    pub piece_id: u32,
+    pub direction: Direction,
+    pub distance: u32,
+}
+
+/// An ogre pushing the adjacent piece one step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushAction {
+    pub piece_id: u32,
+    pub subdirection: SubDirection,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AbilityAction {
     pub piece_id: u32,
+    pub target: AbilityTarget,
+}
+
+/// What the ability is aimed at, most abilities need nothing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbilityTarget {
+    None,
+    Position(Position),
+    Direction(Direction),
+    SubDirection(SubDirection),
+    Summon(PieceType, SubDirection), // portal unit and where to put it
+    Promote(PieceType),
+    Throw(ThrowAction),
+    Push(PushAction),
+    Card(u32), // card id
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
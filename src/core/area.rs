@@ -0,0 +1,233 @@
+use crate::prelude::*;
+
+/// Which side of the caster an area ability hits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Allies,
+    Enemies,
+    Both,
+}
+
+/// Pieces an area ability hits, besides the ones that refuse abilities
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AreaFilter {
+    pub side: Side,
+    pub tag: Option<Tag>, // only pieces with that tag
+}
+
+impl AreaFilter {
+    pub fn new(side: Side, tag: Option<Tag>) -> Self {
+        Self { side, tag }
+    }
+
+    pub fn accepts(&self, caster: &Piece, piece: &Piece) -> bool {
+        let side = match self.side {
+            Side::Allies => piece.color == caster.color,
+            Side::Enemies => piece.color != caster.color,
+            Side::Both => true,
+        };
+        side && piece.id != caster.id && self.tag.is_none_or(|tag| piece.tags.contains(&tag))
+    }
+}
+
+/// An effect applied to every piece of the filter inside the pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AreaAbility {
+    pub pattern: Pattern,
+    pub filter: AreaFilter,
+    pub effect: Effect,
+    pub rounds: u32,
+    pub single_target: bool, // only the chosen piece of the area is hit
+}
+
+impl AreaAbility {
+    pub fn new(pattern: Pattern, filter: AreaFilter, effect: Effect, rounds: u32) -> Self {
+        Self {
+            pattern,
+            filter,
+            effect,
+            rounds,
+            single_target: false,
+        }
+    }
+
+    pub fn with_single_target(mut self) -> Self {
+        self.single_target = true;
+        self
+    }
+}
+
+impl PieceType {
+    /// None if the ability is not an area one, directional shapes use the direction.
+    /// The side is the one of the GDD: the dragon fire only burns enemies,
+    /// the others hit units or pieces of both sides.
+    pub fn get_area_ability(&self, direction: Direction) -> Option<AreaAbility> {
+        match self {
+            PieceType::Dragon => Some(AreaAbility::new(
+                Pattern::new(PatternEnum::get_dragon_fire(direction)),
+                AreaFilter::new(Side::Enemies, None),
+                Effect::Fire,
+                4,
+            )),
+            PieceType::TeslaTower => Some(AreaAbility::new(
+                Pattern::new(PatternEnum::get_cannon_attack()),
+                AreaFilter::new(Side::Both, Some(Tag::Structure)),
+                Effect::Deactivate,
+                6,
+            )),
+            PieceType::Basilisk => Some(AreaAbility::new(
+                Pattern::new(PatternEnum::get_bishop()),
+                AreaFilter::new(Side::Both, None),
+                Effect::Deactivate,
+                4,
+            )),
+            PieceType::Succubus => Some(
+                AreaAbility::new(
+                    Pattern::new(PatternEnum::get_charm()),
+                    AreaFilter::new(Side::Both, None),
+                    Effect::Deactivate,
+                    6,
+                )
+                .with_single_target(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+impl Board {
+    /// Pieces the area would hit from pos, a blockable pattern stops at the first piece
+    pub fn area_targets(
+        &self,
+        pos: Position,
+        caster: &Piece,
+        area: &AreaAbility,
+    ) -> Vec<(Position, u32)> {
+        self.get_squares_with_action(pos, &area.pattern, BasicAction::Ability)
+            .into_iter()
+            .filter_map(|square| square.piece.as_ref().map(|piece| (square.pos, piece)))
+            .filter(|(_, piece)| area.filter.accepts(caster, piece))
+            .filter(|(_, piece)| piece.can_receive_action(&BasicAction::Ability, self))
+            .map(|(pos, piece)| (pos, piece.id))
+            .collect()
+    }
+
+    /// Hits every target of the area, or only the one on target for a single target area
+    pub(crate) fn apply_area(
+        &mut self,
+        pos: Position,
+        caster: &Piece,
+        area: &AreaAbility,
+        target: Option<Position>,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        let mut targets = self.area_targets(pos, caster, area);
+        if area.single_target {
+            self.check_area_target(pos, caster, area, target)?;
+            targets.retain(|(pos, _)| Some(*pos) == target);
+        }
+        for (_, id) in targets {
+            self.apply_ability_effect(id, area.effect.clone(), area.rounds, outcome);
+        }
+        Ok(())
+    }
+
+    /// A single target area needs one of its targets chosen
+    pub(crate) fn check_area_target(
+        &self,
+        pos: Position,
+        caster: &Piece,
+        area: &AreaAbility,
+        target: Option<Position>,
+    ) -> Result<(), RuleError> {
+        if !area.single_target {
+            return Ok(());
+        }
+        let target = target.ok_or(RuleError::InvalidTarget)?;
+        let square = self.get_square(target).ok_or(RuleError::OutOfBoard)?;
+        let piece = square.piece.as_ref().ok_or(RuleError::NoTarget)?;
+        if !piece.can_receive_action(&BasicAction::Ability, self) {
+            return Err(RuleError::ReceiveVetoed);
+        }
+        if self
+            .area_targets(pos, caster, area)
+            .iter()
+            .any(|(pos, _)| *pos == target)
+        {
+            Ok(())
+        } else {
+            Err(RuleError::InvalidTarget)
+        }
+    }
+
+    pub(crate) fn apply_ability_effect(
+        &mut self,
+        id: u32,
+        effect: Effect,
+        rounds: u32,
+        outcome: &mut Outcome,
+    ) {
+        self.add_effect(id, effect.clone(), ChessTime::from_rounds(rounds));
+        outcome.affected.push((id, effect));
+    }
+
+    /// Units around the caster a blast would hit, allies included.
+    /// Units that cannot receive attacks, like HERO or Invulnerable ones, are spared.
+    pub fn blast_targets(&self, pos: Position, caster: &Piece) -> Vec<(Position, u32)> {
+        let pattern = Pattern::new(PatternEnum::get_king());
+        let filter = AreaFilter::new(Side::Both, None);
+        self.get_squares_with_action(pos, &pattern, BasicAction::Attack)
+            .into_iter()
+            .filter_map(|square| square.piece.as_ref().map(|piece| (square.pos, piece)))
+            .filter(|(_, piece)| filter.accepts(caster, piece))
+            .filter(|(_, piece)| piece.can_receive_action(&BasicAction::Attack, self))
+            .map(|(pos, piece)| (pos, piece.id))
+            .collect()
+    }
+
+    /// Damages every unit of blast_targets
+    pub(crate) fn blast(
+        &mut self,
+        pos: Position,
+        caster: &Piece,
+        damage: u32,
+        outcome: &mut Outcome,
+    ) {
+        let targets: Vec<u32> = self
+            .blast_targets(pos, caster)
+            .into_iter()
+            .map(|(_, id)| id)
+            .collect();
+        self.damage_pieces(&targets, caster, damage, outcome);
+    }
+
+    pub(crate) fn damage_pieces(
+        &mut self,
+        ids: &[u32],
+        caster: &Piece,
+        damage: u32,
+        outcome: &mut Outcome,
+    ) {
+        for &id in ids {
+            outcome.damaged.push((id, damage));
+            if self.damage_piece(id, damage, Some(caster.id)) {
+                outcome.killed.push(id);
+            }
+        }
+    }
+
+    /// Every enemy position, for abilities that reach the whole board
+    pub(crate) fn enemy_targets(&self, caster: &Piece) -> Vec<AbilityTarget> {
+        self.board
+            .iter()
+            .flatten()
+            .filter(|square| {
+                square
+                    .piece
+                    .as_ref()
+                    .is_some_and(|piece| piece.color != caster.color)
+            })
+            .map(|square| AbilityTarget::Position(square.pos))
+            .collect()
+    }
+}
@@ -24,9 +24,12 @@ pub struct Board {
     pub common_deck: Vec<PlayedCard>,
     pub cards_on_board: Vec<PlayedCard>,
     pub players: Vec<Player>,
-    pub directions: Vec<Direction>,
+    pub current_player: usize,
+    pub directions: Vec<Direction>, // forward of each color by turn order, empty uses the defaults
     pub time: ChessTime,
+    pub rng: ChessRandom,
     pub events: Vec<Event>,
+    pub cast_outcomes: Vec<(u32, Result<Outcome, RuleError>)>, // (piece_id, result) of resolved casts, see take_cast_outcomes
     pub player_id_generator: u32,
     pub card_id_generator: u32,
     pub piece_id_generator: u32,
@@ -50,9 +53,12 @@ impl Board {
             common_deck: Vec::new(),
             cards_on_board: Vec::new(),
             players: Vec::new(),
+            current_player: 0,
             directions: Vec::new(),
             time: ChessTime::new(),
+            rng: ChessRandom::default(),
             events: Vec::new(),
+            cast_outcomes: Vec::new(),
             player_id_generator: 0,
             card_id_generator: 0,
             piece_id_generator: 0,
@@ -60,6 +66,11 @@ impl Board {
         }
     }
 
+    /// Restarts the rng, the same seed replays the same game
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = ChessRandom::new(seed);
+    }
+
     pub fn get_square(&self, pos: Position) -> Option<&Square> {
         if pos.x >= 0 && pos.y >= 0 {
             self.board.get(pos.y as usize)?.get(pos.x as usize)
@@ -97,10 +108,29 @@ impl Board {
         self.event_id_generator
     }
 
+    /// Adds the player keeping the turn order given by their color
+    pub fn add_player(&mut self, player: Player) {
+        self.players.push(player);
+        self.players.sort_by_key(|player| player.color.turn_order());
+    }
+
+    pub fn get_player(&self, id: u32) -> Option<&Player> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
     pub fn get_player_mut(&mut self, id: u32) -> Option<&mut Player> {
         self.players.iter_mut().find(|player| player.id == id)
     }
 
+    /// The player whose turn it is
+    pub fn get_current_player(&self) -> Option<&Player> {
+        self.players.get(self.current_player)
+    }
+
+    pub fn get_current_player_mut(&mut self) -> Option<&mut Player> {
+        self.players.get_mut(self.current_player)
+    }
+
     pub fn get_played_card(&self, id: u32) -> Option<&PlayedCard> {
         self.common_deck.iter().find(|card| card.card.id == id)
     }
@@ -109,12 +139,36 @@ impl Board {
         self.common_deck.iter_mut().find(|card| card.card.id == id)
     }
 
+    /// Piece standing on the board, dead pieces are in the death pile
     pub fn get_piece(&self, id: u32) -> Option<&Piece> {
-        self.death_pile.iter().find(|piece| piece.id == id)
+        self.find_piece(id).map(|(_, piece)| piece)
     }
 
     pub fn get_piece_mut(&mut self, id: u32) -> Option<&mut Piece> {
-        self.death_pile.iter_mut().find(|piece| piece.id == id)
+        self.board
+            .iter_mut()
+            .flatten()
+            .filter_map(|square| square.piece.as_mut())
+            .find(|piece| piece.id == id)
+    }
+
+    /// Forward of the player of that color: its own forward, else the board directions
+    /// by the turn order of the color, else the default of the color
+    pub fn forward(&self, color: Color) -> Direction {
+        self.get_player_of_color(color)
+            .and_then(|player| player.forward)
+            .or_else(|| self.directions.get(color.turn_order() as usize).copied())
+            .unwrap_or_else(|| color.default_forward())
+    }
+
+    /// New piece with a fresh id, facing the forward of its color
+    pub fn new_piece(&mut self, color: Color, piece_type: PieceType) -> Piece {
+        let id = self.generate_piece_id();
+        Piece::new_facing(id, color, piece_type, self.forward(color))
+    }
+
+    pub fn get_dead_piece(&self, id: u32) -> Option<&Piece> {
+        self.death_pile.iter().find(|piece| piece.id == id)
     }
 
     pub fn get_event(&self, id: u32) -> Option<&Event> {
@@ -125,6 +179,231 @@ impl Board {
         self.events.iter_mut().find(|event| event.id == id)
     }
 
+    // time stuff
+    /// Called once for every movement spent
+    pub fn on_movement(&mut self) {
+        self.time.on_movement();
+        self.rng.on_movement();
+        self.count_down_effects(&BoardAction::Movement);
+        self.count_down_events(&BoardAction::Movement);
+        self.fire_board_action(BoardAction::Movement);
+        self.complete_casts();
+    }
+
+    /// Ends the turn of the current player and starts the turn of the next one
+    pub fn on_turn(&mut self) {
+        if self.players.is_empty() {
+            return;
+        }
+        self.current_player += 1;
+        if self.current_player >= self.players.len() {
+            self.current_player = 0;
+            self.on_round();
+        } else {
+            self.time.on_turn();
+            self.rng.on_turn();
+        }
+        self.count_down_effects(&BoardAction::Turn);
+        self.count_down_events(&BoardAction::Turn);
+        self.fire_board_action(BoardAction::Turn);
+
+        // every player gets one turn per round, so 5 of their turns are 5 rounds
+        let round = self.time.round;
+        if let Some(player) = self.get_current_player_mut() {
+            if round > 0 && round.is_multiple_of(5) {
+                player.max_mana += 1;
+            }
+            player.on_turn_start();
+        }
+
+        // pieces of the player are free again, unless an effect locks them on tick
+        if let Some(color) = self.get_current_player().map(|p| p.color) {
+            for piece in self
+                .board
+                .iter_mut()
+                .flatten()
+                .filter_map(|square| square.piece.as_mut())
+                .filter(|piece| piece.color == color)
+            {
+                piece.moved = false;
+            }
+        }
+        self.tick_effects();
+        self.complete_casts();
+    }
+
+    /// Called by on_turn when the last player ends their turn
+    pub fn on_round(&mut self) {
+        self.time.on_round();
+        self.rng.on_round();
+        self.count_down_effects(&BoardAction::Round);
+        self.count_down_events(&BoardAction::Round);
+        self.fire_board_action(BoardAction::Round);
+    }
+
+    /// The current player ends their turn without spending their movements
+    pub fn pass_turn(&mut self, player_id: u32) -> Result<(), RuleError> {
+        match self.get_current_player() {
+            None => Err(RuleError::NoPlayer),
+            Some(player) if player.id != player_id => Err(RuleError::NotYourTurn),
+            Some(_) => {
+                self.on_turn();
+                Ok(())
+            }
+        }
+    }
+
+    // event stuff
+    /// Queues the function to run once the delay elapses, returns the event id
+    pub fn schedule_event(
+        &mut self,
+        player_id: u32,
+        delay: ChessTime,
+        event_function: EventFunctionEnum,
+    ) -> u32 {
+        let id = self.generate_event_id();
+        self.events
+            .push(Event::new(id, player_id, delay, event_function));
+        id
+    }
+
+    /// Same as schedule_event, the event is linked to the piece that scheduled it
+    pub fn schedule_piece_event(
+        &mut self,
+        piece_id: u32,
+        player_id: u32,
+        delay: ChessTime,
+        event_function: EventFunctionEnum,
+    ) -> u32 {
+        let id = self.generate_event_id();
+        self.events
+            .push(Event::new(id, player_id, delay, event_function).with_source(piece_id));
+        id
+    }
+
+    /// Pending events scheduled by the piece
+    pub fn events_of(&self, piece_id: u32) -> Vec<&Event> {
+        self.events
+            .iter()
+            .filter(|event| event.source_id == Some(piece_id))
+            .collect()
+    }
+
+    /// Brings the events of the piece some rounds closer, due ones run on the next count down
+    pub fn hasten_events_of(&mut self, piece_id: u32, rounds: u32) {
+        self.events
+            .iter_mut()
+            .filter(|event| event.source_id == Some(piece_id))
+            .for_each(|event| event.when = event.when.before_rounds(rounds));
+    }
+
+    pub fn delay_events_of(&mut self, piece_id: u32, rounds: u32) {
+        self.events
+            .iter_mut()
+            .filter(|event| event.source_id == Some(piece_id))
+            .for_each(|event| event.when = event.when.after_rounds(rounds));
+    }
+
+    pub fn cancel_event(&mut self, id: u32) -> Option<Event> {
+        let index = self.events.iter().position(|event| event.id == id)?;
+        Some(self.events.remove(index))
+    }
+
+    /// Lowers the time left of every event, running the ones reaching zero
+    fn count_down_events(&mut self, elapsed: &BoardAction) {
+        self.events
+            .iter_mut()
+            .for_each(|event| event.when.count_down(elapsed));
+        let (due, pending) = self
+            .events
+            .drain(..)
+            .partition(|event| event.when.is_zero());
+        self.events = pending;
+        for event in due {
+            self.run_event(event);
+        }
+    }
+
+    fn run_event(&mut self, event: Event) {
+        match event.event_function {
+            EventFunctionEnum::Summon(pos, piece) => {
+                // the summon is lost if the square got occupied meanwhile
+                let _ = self.enter_piece(pos, piece);
+            }
+            EventFunctionEnum::AddMana => self.add_mana(event.player_id, 1),
+            EventFunctionEnum::AddMovement => self.add_movement(event.player_id, 1),
+            EventFunctionEnum::RemoveMaxMana(amount) => {
+                if let Some(player) = self.get_player_mut(event.player_id) {
+                    player.remove_max_mana(amount);
+                }
+            }
+            EventFunctionEnum::ApplyEffect(pos, effect, duration) => {
+                let id = self
+                    .get_square(pos)
+                    .and_then(|square| square.piece.as_ref())
+                    .map(|piece| piece.id);
+                if let Some(id) = id {
+                    self.add_effect(id, effect, duration);
+                }
+            }
+        }
+    }
+
+    fn tick_effects(&mut self) {
+        let pieces: Vec<Piece> = self
+            .board
+            .iter()
+            .flatten()
+            .filter_map(|square| square.piece.clone())
+            .filter(|piece| !piece.effects.is_empty())
+            .collect();
+        for piece in pieces {
+            piece.on_tick(self);
+        }
+    }
+
+    /// Lowers every effect duration, the ones reaching zero are removed and expire
+    fn count_down_effects(&mut self, elapsed: &BoardAction) {
+        let mut expired = Vec::new();
+        for piece in self
+            .board
+            .iter_mut()
+            .flatten()
+            .filter_map(|square| square.piece.as_mut())
+        {
+            piece
+                .effects
+                .iter_mut()
+                .for_each(|e| e.duration.count_down(elapsed));
+            piece.effects.retain(|e| {
+                if e.duration.is_zero() {
+                    expired.push((piece.id, e.effect.clone()));
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+
+        for (id, effect) in expired {
+            if let Some(piece) = self.get_piece(id).cloned() {
+                effect.on_expire(self, &piece);
+            }
+        }
+    }
+
+    fn fire_board_action(&mut self, action: BoardAction) {
+        let pieces: Vec<Piece> = self
+            .board
+            .iter()
+            .flatten()
+            .filter_map(|square| square.piece.clone())
+            .collect();
+        for piece in pieces {
+            piece.on_board_action(&action, self);
+        }
+    }
+
     // pattern stuff
     #[inline]
     pub fn get_squares(&self, from: Position, pattern: &Pattern) -> Vec<&Square> {
@@ -150,8 +429,10 @@ impl Board {
         pattern: &Pattern,
         action: BasicAction,
     ) -> Vec<&Square> {
-        self.get_squares(from, pattern)
-            .into_iter()
+        self.board
+            .iter()
+            .flatten()
+            .filter(|square| pattern.matches_action(from, square.pos, self, &action))
             .filter(|s| match action {
                 BasicAction::Move => s.piece.is_none(),
                 BasicAction::Attack => s.piece.is_some(),
@@ -174,11 +455,177 @@ impl Board {
             .collect()
     }
 
+    /// Squares strictly between start and end, walking the straight line that joins them.
+    /// Empty if they are not on the same row, column or diagonal.
+    pub fn get_squares_between(&self, start: Position, end: Position) -> Vec<&Square> {
+        let dpos = end - start;
+        if !dpos.is_subdir() {
+            return Vec::new();
+        }
+
+        let step = dpos.signum();
+        let mut squares = Vec::new();
+        let mut pos = start + step;
+        while pos != end {
+            if let Some(square) = self.get_square(pos) {
+                squares.push(square);
+            }
+            pos = pos + step;
+        }
+        squares
+    }
+
     // Piece stuff
-    pub fn kill_piece(&mut self, id: u32) {
-        if let Some(piece) = self.get_piece_mut(id) {
-            piece.alive = false;
+    pub fn find_piece(&self, id: u32) -> Option<(Position, &Piece)> {
+        self.board.iter().flatten().find_map(|square| {
+            square
+                .piece
+                .as_ref()
+                .filter(|piece| piece.id == id)
+                .map(|piece| (square.pos, piece))
+        })
+    }
+
+    /// Every piece on the board of that color and type
+    pub fn find_pieces_of_type(
+        &self,
+        color: Color,
+        piece_type: PieceType,
+    ) -> Vec<(Position, &Piece)> {
+        self.board
+            .iter()
+            .flatten()
+            .filter_map(|square| square.piece.as_ref().map(|piece| (square.pos, piece)))
+            .filter(|(_, piece)| piece.color == color && piece.piece_type == piece_type)
+            .collect()
+    }
+
+    /// First piece of that type not of the given color
+    pub fn find_enemy_piece(
+        &self,
+        color: Color,
+        piece_type: PieceType,
+    ) -> Option<(Position, &Piece)> {
+        self.board.iter().flatten().find_map(|square| {
+            square
+                .piece
+                .as_ref()
+                .filter(|piece| piece.color != color && piece.piece_type == piece_type)
+                .map(|piece| (square.pos, piece))
+        })
+    }
+
+    /// Creates a new piece on an empty square and returns its id
+    pub fn spawn_piece(
+        &mut self,
+        pos: Position,
+        color: Color,
+        piece_type: PieceType,
+    ) -> Result<u32, RuleError> {
+        match self.get_square(pos) {
+            None => return Err(RuleError::OutOfBoard),
+            Some(square) if square.piece.is_some() => return Err(RuleError::Occupied),
+            Some(_) => {}
+        }
+        let piece = self.new_piece(color, piece_type);
+        let id = piece.id;
+        self.enter_piece(pos, piece)?;
+        Ok(id)
+    }
+
+    /// Places a piece arriving on the board, new, summoned, raised or transformed.
+    /// Its autocast starts here, moving pieces around goes through place_piece instead.
+    pub fn enter_piece(&mut self, pos: Position, piece: Piece) -> Result<(), RuleError> {
+        let id = piece.id;
+        self.place_piece(pos, piece)?;
+        self.start_autocast(id);
+        Ok(())
+    }
+
+    pub fn place_piece(&mut self, pos: Position, piece: Piece) -> Result<(), RuleError> {
+        let square = self.get_square_mut(pos).ok_or(RuleError::OutOfBoard)?;
+        if square.piece.is_some() {
+            return Err(RuleError::Occupied);
+        }
+        square.piece = Some(piece);
+        Ok(())
+    }
+
+    /// Takes the piece out of the board without killing it
+    pub fn remove_piece(&mut self, pos: Position) -> Option<Piece> {
+        self.get_square_mut(pos)?.piece.take()
+    }
+
+    pub fn move_piece(&mut self, from: Position, to: Position) -> Result<(), RuleError> {
+        match self.get_square(to) {
+            None => return Err(RuleError::OutOfBoard),
+            Some(square) if square.piece.is_some() => return Err(RuleError::Occupied),
+            Some(_) => {}
+        }
+        let piece = self.remove_piece(from).ok_or(RuleError::NoPiece)?;
+        self.place_piece(to, piece)
+    }
+
+    /// Replaces the piece with a new one of the given type on the same square.
+    /// It keeps its id, color and effects, and receives a Transform action.
+    pub fn transform_piece(&mut self, id: u32, piece_type: PieceType) -> Result<(), RuleError> {
+        let (pos, _) = self.find_piece(id).ok_or(RuleError::NoPiece)?;
+        let old = self.remove_piece(pos).ok_or(RuleError::NoPiece)?;
+        let mut piece = Piece::new_facing(id, old.color, piece_type, self.forward(old.color));
+        piece.moved = old.moved;
+        piece.effects = old.effects;
+        self.enter_piece(pos, piece.clone())?;
+        piece.on_action_received(
+            &PieceAction::Transform(TransformAction {
+                piece_id: id,
+                from: old.piece_type,
+                to: piece_type,
+            }),
+            self,
+        );
+        Ok(())
+    }
+
+    /// Moves the piece from its square into the death pile, firing the Die hooks
+    pub fn kill_piece(&mut self, id: u32) -> Option<Piece> {
+        let (pos, _) = self.find_piece(id)?;
+        let mut piece = self.remove_piece(pos)?;
+        piece.alive = false;
+        self.death_pile.push(piece.clone());
+        piece.on_action_received(&PieceAction::Die(pos), self);
+        // the units a necromancer controls die with it
+        for controlled in piece.piece_list() {
+            self.kill_piece(controlled.id);
+        }
+        Some(piece)
+    }
+
+    /// Same as kill_piece, also firing the Kill hooks of the killer
+    pub fn kill_piece_by(&mut self, id: u32, killer_id: u32) -> Option<Piece> {
+        let piece = self.kill_piece(id)?;
+        if let Some((pos, killer)) = self.find_piece(killer_id) {
+            let killer = killer.clone();
+            killer.on_action_done(&PieceAction::Kill(pos), self);
+        }
+        Some(piece)
+    }
+
+    /// Lowers the current life of the piece, it only dies when it reaches zero.
+    /// Returns true if the piece died.
+    pub fn damage_piece(&mut self, id: u32, damage: u32, killer_id: Option<u32>) -> bool {
+        let Some(piece) = self.get_piece_mut(id) else {
+            return false;
+        };
+        let life = piece.current_life().saturating_sub(damage);
+        piece.set_current_life(life);
+        if life > 0 {
+            return false;
+        }
+        match killer_id {
+            Some(killer_id) => self.kill_piece_by(id, killer_id),
+            None => self.kill_piece(id),
         }
+        .is_some()
     }
 
     pub fn set_piece_moved(&mut self, id: u32, moved: bool) {
@@ -188,25 +635,32 @@ impl Board {
     }
 
     pub fn halve_effect(&mut self, id: u32, effect: Effect) {
-        if let Some(piece) = self.get_piece_mut(id) {
-            if let Some(effect) = piece.effects.iter_mut().find(|e| e.effect == effect) {
-                effect.duration /= 2;
-            }
+        if let Some(piece) = self.get_piece_mut(id)
+            && let Some(effect) = piece.effects.iter_mut().find(|e| e.effect == effect)
+        {
+            effect.duration /= 2;
         }
     }
 
     pub fn double_effect(&mut self, id: u32, effect: Effect) {
-        if let Some(piece) = self.get_piece_mut(id) {
-            if let Some(effect) = piece.effects.iter_mut().find(|e| e.effect == effect) {
-                effect.duration *= 2;
-            }
+        if let Some(piece) = self.get_piece_mut(id)
+            && let Some(effect) = piece.effects.iter_mut().find(|e| e.effect == effect)
+        {
+            effect.duration *= 2;
         }
     }
 
+    /// The only way to apply an effect, so on_apply is always fired
     pub fn add_effect(&mut self, id: u32, effect: Effect, duration: ChessTime) {
-        if let Some(piece) = self.get_piece_mut(id) {
-            piece.effects.push(AppliedEffect { effect, duration });
-        }
+        let Some(piece) = self.get_piece_mut(id) else {
+            return;
+        };
+        piece.effects.push(AppliedEffect {
+            effect: effect.clone(),
+            duration,
+        });
+        let piece = piece.clone();
+        effect.on_apply(self, &piece);
     }
 
     pub fn remove_effect(&mut self, id: u32, effect: Effect) {
@@ -215,6 +669,34 @@ impl Board {
         }
     }
 
+    /// Gives the tag to the piece for good, false if it already had it
+    pub fn add_tag(&mut self, id: u32, tag: Tag) -> bool {
+        let Some(piece) = self.get_piece_mut(id) else {
+            return false;
+        };
+        if piece.tags.contains(&tag) {
+            return false;
+        }
+        piece.tags.push(tag);
+        let piece = piece.clone();
+        tag.on_added(self, &piece);
+        true
+    }
+
+    /// Takes the tag from the piece, false if it did not have it
+    pub fn remove_tag(&mut self, id: u32, tag: Tag) -> bool {
+        let Some(piece) = self.get_piece_mut(id) else {
+            return false;
+        };
+        if !piece.tags.contains(&tag) {
+            return false;
+        }
+        piece.tags.retain(|t| *t != tag);
+        let piece = piece.clone();
+        tag.on_removed(self, &piece);
+        true
+    }
+
     // Player stuff
     pub fn add_mana(&mut self, player_id: u32, amount: u32) {
         if let Some(player) = self.get_player_mut(player_id) {
@@ -0,0 +1,64 @@
+use crate::prelude::*;
+
+/// Walls a builder puts in a line
+pub const WALL_COUNT: i32 = 3;
+
+impl Board {
+    /// Squares of the wall line in front of the builder, stops at the end of the board
+    pub fn wall_line(&self, pos: Position, direction: Direction) -> Vec<Position> {
+        let step = direction.to_position();
+        (1..=WALL_COUNT)
+            .map(|distance| Position::new(pos.x + step.x * distance, pos.y + step.y * distance))
+            .take_while(|wall_pos| self.get_square(*wall_pos).is_some())
+            .collect()
+    }
+
+    // Builder
+    pub(crate) fn check_build_walls(
+        &self,
+        pos: Position,
+        direction: Direction,
+    ) -> Result<(), RuleError> {
+        let line = self.wall_line(pos, direction);
+        if line.is_empty() {
+            return Err(RuleError::OutOfBoard);
+        }
+        // a partially blocked line is fine, it only needs room for one wall
+        if line.iter().all(|wall_pos| {
+            self.get_square(*wall_pos)
+                .is_some_and(|s| s.piece.is_some())
+        }) {
+            return Err(RuleError::Occupied);
+        }
+        Ok(())
+    }
+
+    /// Puts a wall on every empty square of the line. Occupied squares are skipped,
+    /// a piece in the way does not stop the walls beyond it.
+    pub(crate) fn build_walls(
+        &mut self,
+        pos: Position,
+        builder: &Piece,
+        direction: Direction,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        self.check_build_walls(pos, direction)?;
+        for wall_pos in self.wall_line(pos, direction) {
+            if let Ok(id) = self.spawn_piece(wall_pos, builder.color, PieceType::Wall) {
+                outcome.spawned.push(id);
+            }
+        }
+        Ok(())
+    }
+
+    /// A wall deconstructs itself, it is not killed by anyone
+    pub(crate) fn deconstruct_wall(
+        &mut self,
+        wall: &Piece,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        self.kill_piece(wall.id).ok_or(RuleError::NoPiece)?;
+        outcome.killed.push(wall.id);
+        Ok(())
+    }
+}
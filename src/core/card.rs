@@ -1,3 +1,5 @@
+use crate::prelude::*;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Card {
     pub id: u32,
@@ -53,4 +55,27 @@ impl Deck {
     pub fn has_card(&self, card_id: u32) -> bool {
         self.cards.iter().any(|card| card.id == card_id)
     }
+
+    pub fn get_card(&self, card_id: u32) -> Option<&Card> {
+        self.cards.iter().find(|card| card.id == card_id)
+    }
+
+    /// Takes the card on top of the deck, the last one
+    pub fn draw(&mut self) -> Option<Card> {
+        self.cards.pop()
+    }
+
+    /// Fisher-Yates with the board rng, so replays shuffle the same way
+    pub fn shuffle(&mut self, rng: &mut ChessRandom) {
+        for i in (1..self.cards.len()).rev() {
+            let j = rng.next_below(i as u64 + 1) as usize;
+            self.cards.swap(i, j);
+        }
+    }
+
+    /// Removes the card with the given id, if any
+    pub fn take_card(&mut self, card_id: u32) -> Option<Card> {
+        let index = self.cards.iter().position(|card| card.id == card_id)?;
+        self.remove_card(index)
+    }
 }
@@ -0,0 +1,92 @@
+use crate::prelude::*;
+
+/// Farthest a catapult can throw
+pub const MAX_THROW_DISTANCE: u32 = 10;
+
+impl Board {
+    /// Where the thrown piece lands, it may be out of the board
+    pub fn throw_landing(&self, pos: Position, throw: &ThrowAction) -> Position {
+        let step = throw.direction.to_position();
+        let distance = throw.distance as i32;
+        Position::new(pos.x + step.x * distance, pos.y + step.y * distance)
+    }
+
+    // Catapult
+    pub(crate) fn check_throw(&self, pos: Position, throw: &ThrowAction) -> Result<(), RuleError> {
+        if throw.distance == 0 || throw.distance > MAX_THROW_DISTANCE {
+            return Err(RuleError::InvalidTarget);
+        }
+        let (piece_pos, piece) = self.find_piece(throw.piece_id).ok_or(RuleError::NoTarget)?;
+        // own or enemy, it only has to be next to the catapult
+        if pos.jump_distance(&piece_pos) != 1 || !piece.is_trans() {
+            return Err(RuleError::InvalidTarget);
+        }
+        if !piece.can_receive_action(&BasicAction::Ability, self) {
+            return Err(RuleError::ReceiveVetoed);
+        }
+        if self.throw_landing(pos, throw) == piece_pos {
+            return Err(RuleError::InvalidTarget);
+        }
+        Ok(())
+    }
+
+    /// Throws the piece over everything in between. Out of the board or on an IMP or
+    /// immune unit the thrown piece dies, on any other unit that unit dies instead.
+    pub(crate) fn throw_piece(
+        &mut self,
+        pos: Position,
+        catapult: &Piece,
+        throw: &ThrowAction,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        self.check_throw(pos, throw)?;
+        let (from, _) = self.find_piece(throw.piece_id).ok_or(RuleError::NoTarget)?;
+        let to = self.throw_landing(pos, throw);
+
+        let Some(square) = self.get_square(to) else {
+            if self.kill_piece_by(throw.piece_id, catapult.id).is_some() {
+                outcome.killed.push(throw.piece_id);
+            }
+            return Ok(());
+        };
+        if let Some(occupant) = square.piece.clone() {
+            if occupant.is_imp() || !occupant.can_receive_action(&BasicAction::Ability, self) {
+                if self.kill_piece_by(throw.piece_id, catapult.id).is_some() {
+                    outcome.killed.push(throw.piece_id);
+                }
+                return Ok(());
+            }
+            if self.kill_piece_by(occupant.id, throw.piece_id).is_some() {
+                outcome.killed.push(occupant.id);
+            }
+        }
+
+        self.move_piece(from, to)?;
+        outcome.moved.push(FromToAction {
+            from,
+            to,
+            piece_id: throw.piece_id,
+        });
+        Ok(())
+    }
+
+    /// Every throw of the adjacent TRANS pieces, used to generate the legal actions
+    pub(crate) fn throw_targets(&self, pos: Position) -> Vec<AbilityTarget> {
+        SubDirection::all()
+            .into_iter()
+            .filter_map(|subdirection| self.get_square(pos + subdirection)?.piece.as_ref())
+            .filter(|piece| piece.is_trans())
+            .flat_map(|piece| {
+                Direction::all().into_iter().flat_map(move |direction| {
+                    (1..=MAX_THROW_DISTANCE).map(move |distance| {
+                        AbilityTarget::Throw(ThrowAction {
+                            piece_id: piece.id,
+                            direction,
+                            distance,
+                        })
+                    })
+                })
+            })
+            .collect()
+    }
+}
@@ -43,8 +43,8 @@ impl Effect {
     /// Called when the effect is applied
     pub fn on_apply(&self, board: &mut Board, piece: &Piece) {
         match self {
-            Effect::Ice if piece.is_str() => {
-                board.halve_effect(piece.id, Effect::Ice);
+            Effect::Fire | Effect::Ice if piece.is_str() => {
+                board.halve_effect(piece.id, self.clone());
             }
             Effect::Deactivate if !piece.is_str() => {
                 board.halve_effect(piece.id, Effect::Deactivate);
@@ -55,6 +55,7 @@ impl Effect {
 
     pub fn on_action_done(&self, _board: &mut Board, _piece: &Piece) {}
     pub fn on_action_received(&self, _board: &mut Board, _piece: &Piece) {}
+    pub fn on_board_action(&self, _board: &mut Board, _piece: &Piece, _action: &BoardAction) {}
 
     pub fn can_action_be_done(&self, _board: &Board, _piece: &Piece) -> bool {
         true
@@ -6,14 +6,17 @@ pub struct Event {
     pub player_id: u32,
     pub when: ChessTime,
     pub event_function: EventFunctionEnum,
+    pub source_id: Option<u32>, // piece that scheduled it
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 pub enum EventFunctionEnum {
-    Summon(Piece),
+    Summon(Position, Piece),
     AddMana,
     AddMovement,
-    ApplyEffect(Position),
+    RemoveMaxMana(u32), // end of a temporary max mana modifier
+    ApplyEffect(Position, Effect, ChessTime), // (where, effect, duration)
 }
 
 impl Event {
@@ -28,6 +31,12 @@ impl Event {
             player_id,
             when,
             event_function,
+            source_id: None,
         }
     }
+
+    pub fn with_source(mut self, piece_id: u32) -> Self {
+        self.source_id = Some(piece_id);
+        self
+    }
 }
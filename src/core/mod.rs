@@ -1,23 +1,43 @@
 pub mod ability;
 pub mod action;
+pub mod area;
 pub mod board;
+pub mod builder;
 pub mod card;
+pub mod catapult;
 pub mod effect;
 pub mod event;
+pub mod necromancer;
+pub mod ogre;
+pub mod oni;
 pub mod pattern;
 pub mod piece;
 pub mod player;
+pub mod portal;
 pub mod position;
+pub mod ram;
+pub mod random;
+pub mod resource;
+pub mod rule;
+pub mod spider;
+pub mod tags;
 pub mod time;
 
 pub use ability::*;
 pub use action::*;
+pub use area::*;
 pub use board::*;
+pub use builder::*;
 pub use card::*;
+pub use catapult::*;
 pub use effect::*;
 pub use event::*;
 pub use pattern::*;
 pub use piece::*;
 pub use player::*;
+pub use portal::*;
 pub use position::*;
+pub use ram::*;
+pub use random::*;
+pub use rule::*;
 pub use time::*;
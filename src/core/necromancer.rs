@@ -0,0 +1,67 @@
+use crate::prelude::*;
+
+impl Board {
+    // Necromancer
+    pub(crate) fn check_raise_dead(
+        &self,
+        necromancer: &Piece,
+        target: Position,
+    ) -> Result<(), RuleError> {
+        let square = self.get_square(target).ok_or(RuleError::OutOfBoard)?;
+        let piece = square.piece.as_ref().ok_or(RuleError::NoTarget)?;
+        if piece.color == necromancer.color {
+            return Err(RuleError::FriendlyTarget);
+        }
+        if piece.is_dead() {
+            return Err(RuleError::InvalidTarget);
+        }
+        if !piece.can_receive_action(&BasicAction::Ability, self) {
+            return Err(RuleError::ReceiveVetoed);
+        }
+        Ok(())
+    }
+
+    /// Kills the enemy and brings it back on its square under the necromancer color,
+    /// with DEAD as its only tag. It is listed on the necromancer and dies with it.
+    pub(crate) fn raise_dead(
+        &mut self,
+        necromancer: &Piece,
+        target: Position,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        self.check_raise_dead(necromancer, target)?;
+        let id = self
+            .get_square(target)
+            .and_then(|square| square.piece.as_ref())
+            .map(|piece| piece.id)
+            .ok_or(RuleError::NoTarget)?;
+        let dead = self
+            .kill_piece_by(id, necromancer.id)
+            .ok_or(RuleError::NoTarget)?;
+        outcome.killed.push(id);
+
+        // its Die hooks may have filled the square
+        if self
+            .get_square(target)
+            .is_none_or(|square| square.piece.is_some())
+        {
+            return Ok(());
+        }
+        self.death_pile.retain(|piece| piece.id != id);
+        let mut piece = Piece::new_facing(
+            id,
+            necromancer.color,
+            dead.piece_type,
+            self.forward(necromancer.color),
+        );
+        piece.tags = vec![Tag::Dead];
+        piece.moved = true;
+        self.enter_piece(target, piece.clone())?;
+        outcome.spawned.push(id);
+
+        if let Some(necromancer) = self.get_piece_mut(necromancer.id) {
+            necromancer.add_to_piece_list(piece);
+        }
+        Ok(())
+    }
+}
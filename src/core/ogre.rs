@@ -0,0 +1,70 @@
+use crate::prelude::*;
+
+impl Board {
+    // Ogre
+    pub(crate) fn check_push(&self, pos: Position, push: &PushAction) -> Result<(), RuleError> {
+        let (piece_pos, piece) = self.find_piece(push.piece_id).ok_or(RuleError::NoTarget)?;
+        if pos.jump_distance(&piece_pos) != 1 {
+            return Err(RuleError::InvalidTarget);
+        }
+        if !piece.can_receive_action(&BasicAction::Ability, self) {
+            return Err(RuleError::ReceiveVetoed);
+        }
+        let to = piece_pos + push.subdirection;
+        if to == pos {
+            return Err(RuleError::InvalidTarget);
+        }
+        self.get_square(to).ok_or(RuleError::OutOfBoard)?;
+        Ok(())
+    }
+
+    /// Pushes the piece one step. A unit where it lands dies, unless that unit is
+    /// immune, then the pushed piece dies instead.
+    pub(crate) fn push_piece(
+        &mut self,
+        pos: Position,
+        ogre: &Piece,
+        push: &PushAction,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        self.check_push(pos, push)?;
+        let (from, _) = self.find_piece(push.piece_id).ok_or(RuleError::NoTarget)?;
+        let to = from + push.subdirection;
+
+        if let Some(occupant) = self.get_square(to).and_then(|square| square.piece.clone()) {
+            if !occupant.can_receive_action(&BasicAction::Ability, self) {
+                if self.kill_piece_by(push.piece_id, ogre.id).is_some() {
+                    outcome.killed.push(push.piece_id);
+                }
+                return Ok(());
+            }
+            if self.kill_piece_by(occupant.id, push.piece_id).is_some() {
+                outcome.killed.push(occupant.id);
+            }
+        }
+
+        self.move_piece(from, to)?;
+        outcome.moved.push(FromToAction {
+            from,
+            to,
+            piece_id: push.piece_id,
+        });
+        Ok(())
+    }
+
+    /// Every push of the adjacent pieces, used to generate the legal actions
+    pub(crate) fn push_targets(&self, pos: Position) -> Vec<AbilityTarget> {
+        SubDirection::all()
+            .into_iter()
+            .filter_map(|subdirection| self.get_square(pos + subdirection)?.piece.as_ref())
+            .flat_map(|piece| {
+                SubDirection::all().into_iter().map(|subdirection| {
+                    AbilityTarget::Push(PushAction {
+                        piece_id: piece.id,
+                        subdirection,
+                    })
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,38 @@
+use crate::prelude::*;
+
+impl Board {
+    // Oni
+    /// Where the oni lands next to the enemy magician. A dead magician gives no target.
+    pub(crate) fn check_oni_teleport(
+        &self,
+        oni: &Piece,
+        subdirection: SubDirection,
+    ) -> Result<Position, RuleError> {
+        let (magician_pos, _) = self
+            .find_enemy_piece(oni.color, PieceType::Magician)
+            .ok_or(RuleError::NoTarget)?;
+        let to = magician_pos + subdirection;
+        match self.get_square(to) {
+            None => Err(RuleError::OutOfBoard),
+            Some(square) if square.piece.is_some() => Err(RuleError::Occupied),
+            Some(_) => Ok(to),
+        }
+    }
+
+    pub(crate) fn oni_teleport(
+        &mut self,
+        pos: Position,
+        oni: &Piece,
+        subdirection: SubDirection,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        let to = self.check_oni_teleport(oni, subdirection)?;
+        self.move_piece(pos, to)?;
+        outcome.moved.push(FromToAction {
+            from: pos,
+            to,
+            piece_id: oni.id,
+        });
+        Ok(())
+    }
+}
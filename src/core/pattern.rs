@@ -6,9 +6,12 @@ pub enum PatternEnum {
     DirectionalPattern(i32),    // Rook is -1, Structure is 1, Magician is 2, Balista is 6
     DiagonalPattern(i32),       // Bishop is -1, Leech is 1
     CirclePattern(i32),         // Archer is 4
-    SquarePattern(i32),         // Cannon is 3
+    SquarePattern(i32),         // Cannon is 3, Charm is -1
     KnightPattern(i32, i32),    // (dx = 2 & dy = 1) | (dx = 1 & dy = 2)
 
+    // Shape with Forward
+    RectanglePattern(Direction, i32, i32), // (forward, width, length) Dragon fire is 3x5
+
     // DirectionalPattern with Forward
     PawnMovePattern(Direction),      // 2 no Forward
     PawnTakePattern(Direction),      // 1 no ForwardDiagonals
@@ -19,6 +22,11 @@ pub enum PatternEnum {
     RandomizablePattern(Box<PatternEnum>), // (pattern)
     // CrazyPawnPattern is RandomizablePattern with SubdirectionalPattern(2)
 
+    // PassThroughPattern
+    PassThroughPattern(Box<PatternEnum>), // (pattern) goes through pieces, except IMP on attacks
+    // MagicianPattern is PassThroughPattern with DirectionalPattern(2)
+    // BalistaAttackPattern is PassThroughPattern with DirectionalPattern(6)
+
     // CompositePattern
     Composite(Vec<PatternEnum>),
 
@@ -63,6 +71,18 @@ impl Pattern {
     pub fn matches(&self, start: Position, end: Position, board: &Board) -> bool {
         self.patterns.iter().any(|p| p.matches(start, end, board))
     }
+
+    pub fn matches_action(
+        &self,
+        start: Position,
+        end: Position,
+        board: &Board,
+        action: &BasicAction,
+    ) -> bool {
+        self.patterns
+            .iter()
+            .any(|p| p.matches_action(start, end, board, action))
+    }
 }
 
 impl PatternEnum {
@@ -70,7 +90,63 @@ impl PatternEnum {
         Self::Composite(patterns)
     }
 
+    /// Same as matches_action with a Move, so no piece can be in the way
     pub fn matches(&self, start: Position, end: Position, board: &Board) -> bool {
+        self.matches_action(start, end, board, &BasicAction::Move)
+    }
+
+    /// Checks the geometry and the squares between start and end.
+    /// The end square itself is never checked, so a blocking piece is
+    /// excluded for Move and included for Take and Attack by the caller.
+    pub fn matches_action(
+        &self,
+        start: Position,
+        end: Position,
+        board: &Board,
+        action: &BasicAction,
+    ) -> bool {
+        match self {
+            Self::PassThroughPattern(pattern) => {
+                pattern.fits(start, end, board)
+                    && (action != &BasicAction::Attack
+                        || board
+                            .get_squares_between(start, end)
+                            .iter()
+                            .all(|square| !square.piece.as_ref().is_some_and(|p| p.is_imp())))
+            }
+            Self::RandomizablePattern(pattern) => {
+                pattern.matches_action(start, end, board, action) && self.fits(start, end, board)
+            }
+            Self::Composite(patterns) => patterns
+                .iter()
+                .any(|p| p.matches_action(start, end, board, action)),
+            _ => {
+                self.fits(start, end, board)
+                    && (!self.is_blockable()
+                        || board
+                            .get_squares_between(start, end)
+                            .iter()
+                            .all(|square| square.piece.is_none()))
+            }
+        }
+    }
+
+    /// Patterns that walk a ray and cannot go through pieces
+    pub fn is_blockable(&self) -> bool {
+        matches!(
+            self,
+            Self::SubdirectionalPattern(_)
+                | Self::DirectionalPattern(_)
+                | Self::DiagonalPattern(_)
+                | Self::PawnMovePattern(_)
+                | Self::PawnTakePattern(_)
+                | Self::SuperPawnMovePattern(_)
+                | Self::SuperPawnTakePattern(_)
+        )
+    }
+
+    /// Only the geometry, pieces in the way are ignored
+    fn fits(&self, start: Position, end: Position, board: &Board) -> bool {
         match self {
             Self::SubdirectionalPattern(n) => {
                 let dpos = end - start;
@@ -122,7 +198,8 @@ impl PatternEnum {
             }
             Self::SquarePattern(n) => {
                 let dpos = end - start;
-                dpos.jump_length() <= *n
+                // If n is -1, the whole board
+                *n == -1 || dpos.jump_length() <= *n
             }
             Self::KnightPattern(dx, dy) => {
                 let dpos = end - start;
@@ -132,6 +209,15 @@ impl PatternEnum {
                 // Check if movement matches either knight pattern
                 (abs_dx == *dx && abs_dy == *dy) || (abs_dx == *dy && abs_dy == *dx)
             }
+            Self::RectanglePattern(forward, width, length) => {
+                // starts in front of start, centered on the forward line
+                let dpos = end - start;
+                let forward_pos = forward.to_position();
+                let side_pos = forward.axis().other().to_position();
+                let along = dpos.x * forward_pos.x + dpos.y * forward_pos.y;
+                let side = dpos.x * side_pos.x + dpos.y * side_pos.y;
+                along >= 1 && along <= *length && side.abs() <= *width / 2
+            }
             Self::PawnMovePattern(forward) => {
                 let dpos = end - start;
                 dpos == forward.to_position()
@@ -139,16 +225,14 @@ impl PatternEnum {
             Self::PawnTakePattern(forward) => {
                 let dpos = end - start;
                 let forward_pos = forward.to_position();
-                let right_pos = Direction::Right.to_position();
-                let left_pos = Direction::Left.to_position();
+                let [right_pos, left_pos] = sides(*forward).map(|side| side.to_position());
 
                 dpos == (forward_pos + right_pos) || dpos == (forward_pos + left_pos)
             }
             Self::SuperPawnMovePattern(forward) => {
                 let dpos = end - start;
                 let forward_pos = forward.to_position();
-                let right_pos = Direction::Right.to_position();
-                let left_pos = Direction::Left.to_position();
+                let [right_pos, left_pos] = sides(*forward).map(|side| side.to_position());
 
                 dpos == forward_pos
                     || dpos == (forward_pos + right_pos)
@@ -160,25 +244,37 @@ impl PatternEnum {
             Self::SuperPawnTakePattern(forward) => {
                 let dpos = end - start;
                 let forward_pos = forward.to_position();
-                let right_pos = Direction::Right.to_position();
-                let left_pos = Direction::Left.to_position();
+                let [right_pos, left_pos] = sides(*forward).map(|side| side.to_position());
 
                 dpos == forward_pos
                     || dpos == (forward_pos + right_pos)
                     || dpos == (forward_pos + left_pos)
             }
             Self::RandomizablePattern(pattern) => {
-                // The random numbers are between 0 and 1
-                // and they generate a random direction, subdirection, or other stuff
-                // the random numbers are obtained from the board state, so there is a random dir, subdir, etc for each ChessTime component (Movement, Turn, Round)
+                // The random values are obtained from the board state, there is a new one
+                // for each ChessTime component (Movement, Turn, Round), the turn one is used
                 // a subdirectional pattern will comply with the random subdirection
                 // a directional pattern will comply with the random direction
                 // a diagonal pattern will comply with the random diagonal
-                // no other pattern is supported yet
-                // For now, we'll just use the pattern's matching logic
-                pattern.matches(start, end, board)
+                // no other pattern is supported yet, they just use their own logic
+                let value = board.rng.turn_rng;
+                let step = (end - start).signum();
+                pattern.fits(start, end, board)
+                    && match pattern.as_ref() {
+                        Self::SubdirectionalPattern(_) => {
+                            step == ChessRandom::to_subdirection(value).to_position()
+                        }
+                        Self::DirectionalPattern(_) => {
+                            step == ChessRandom::to_direction(value).to_position()
+                        }
+                        Self::DiagonalPattern(_) => {
+                            step == ChessRandom::to_diagonal(value).to_position()
+                        }
+                        _ => true,
+                    }
             }
-            Self::Composite(patterns) => patterns.iter().any(|p| p.matches(start, end, board)),
+            Self::PassThroughPattern(pattern) => pattern.fits(start, end, board),
+            Self::Composite(patterns) => patterns.iter().any(|p| p.fits(start, end, board)),
             Self::PawnAbilityPattern(forward) => {
                 // if end == start and forward is out of the board, return true
                 if end == start {
@@ -192,6 +288,12 @@ impl PatternEnum {
     }
 }
 
+/// The two directions perpendicular to forward, so any forward works
+fn sides(forward: Direction) -> [Direction; 2] {
+    let side = forward.axis().other();
+    [side.to_direction(1), side.to_direction(-1)]
+}
+
 // Helper functions to create patterns
 impl PatternEnum {
     // Classic Patterns
@@ -229,11 +331,11 @@ impl PatternEnum {
     }
 
     pub fn get_magician() -> Self {
-        Self::DirectionalPattern(2)
+        Self::PassThroughPattern(Box::new(Self::DirectionalPattern(2)))
     }
 
     pub fn get_balista_attack() -> Self {
-        Self::DirectionalPattern(6)
+        Self::PassThroughPattern(Box::new(Self::DirectionalPattern(6)))
     }
 
     pub fn get_leech_take() -> Self {
@@ -269,6 +371,14 @@ impl PatternEnum {
         Self::get_square(3)
     }
 
+    pub fn get_charm() -> Self {
+        Self::get_square(-1)
+    }
+
+    pub fn get_dragon_fire(forward: Direction) -> Self {
+        Self::RectanglePattern(forward, 3, 5)
+    }
+
     // Other Patterns
     pub fn get_crazy_pawn() -> Self {
         Self::RandomizablePattern(Box::new(Self::SubdirectionalPattern(2)))
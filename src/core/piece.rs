@@ -24,6 +24,14 @@ pub enum Tag {
 impl Tag {
     pub fn on_action_done(&self, _board: &mut Board, _piece: &Piece, _action: &PieceAction) {}
 
+    pub fn on_board_action(&self, _board: &mut Board, _piece: &Piece, _action: &BoardAction) {}
+
+    /// Called when the tag is given to the piece at runtime
+    pub fn on_added(&self, _board: &mut Board, _piece: &Piece) {}
+
+    /// Called when the tag is taken from the piece at runtime
+    pub fn on_removed(&self, _board: &mut Board, _piece: &Piece) {}
+
     pub fn on_action_received(&self, board: &mut Board, piece: &Piece, action: &PieceAction) {
         if self == &Tag::Dead && matches!(action, PieceAction::Die(_)) {
             return;
@@ -214,10 +222,11 @@ impl PieceType {
         }
     }
 
-    pub fn get_move_pattern(&self) -> Pattern {
+    /// forward is only used by pawn like pieces
+    pub fn get_move_pattern(&self, forward: Direction) -> Pattern {
         match self {
             // Classic Pieces
-            PieceType::Pawn => Pattern::new(PatternEnum::PawnMovePattern(Direction::Up)),
+            PieceType::Pawn => Pattern::new(PatternEnum::PawnMovePattern(forward)),
             PieceType::Bishop => Pattern::new(PatternEnum::DiagonalPattern(-1)),
             PieceType::Knight => Pattern::new(PatternEnum::KnightPattern(2, 1)),
             PieceType::Rook => Pattern::new(PatternEnum::DirectionalPattern(-1)),
@@ -229,28 +238,28 @@ impl PieceType {
 
             // Starting Pieces
             PieceType::Archer => Pattern::new_many(vec![
-                PatternEnum::DirectionalPattern(2),
+                PatternEnum::get_magician(),
                 PatternEnum::SubdirectionalPattern(1),
             ]),
             PieceType::Balista => Pattern::new(PatternEnum::DirectionalPattern(1)),
-            PieceType::Builder => Pattern::new(PatternEnum::DirectionalPattern(2)),
+            PieceType::Builder => Pattern::new(PatternEnum::get_magician()),
             PieceType::Cannon => Pattern::new(PatternEnum::DirectionalPattern(1)),
             PieceType::Catapult => Pattern::new(PatternEnum::DirectionalPattern(1)),
             PieceType::CrazyPawn => Pattern::new(PatternEnum::RandomizablePattern(Box::new(
                 PatternEnum::SubdirectionalPattern(2),
             ))),
-            PieceType::Magician => Pattern::new(PatternEnum::DirectionalPattern(2)),
+            PieceType::Magician => Pattern::new(PatternEnum::get_magician()),
             PieceType::Paladin => Pattern::new_many(vec![
                 PatternEnum::DirectionalPattern(-1),
                 PatternEnum::DiagonalPattern(-1),
             ]),
             PieceType::Ram => Pattern::new(PatternEnum::DirectionalPattern(1)),
-            PieceType::ShieldBearer => Pattern::new(PatternEnum::PawnMovePattern(Direction::Up)),
-            PieceType::Ship => Pattern::new(PatternEnum::DirectionalPattern(2)),
-            PieceType::SuperPawn => Pattern::new(PatternEnum::SuperPawnMovePattern(Direction::Up)),
-            PieceType::TeslaTower => Pattern::new(PatternEnum::DirectionalPattern(2)),
+            PieceType::ShieldBearer => Pattern::new(PatternEnum::PawnMovePattern(forward)),
+            PieceType::Ship => Pattern::new(PatternEnum::get_magician()),
+            PieceType::SuperPawn => Pattern::new(PatternEnum::SuperPawnMovePattern(forward)),
+            PieceType::TeslaTower => Pattern::new(PatternEnum::get_magician()),
             PieceType::Wall => Pattern::new_null(),
-            PieceType::Warlock => Pattern::new(PatternEnum::DirectionalPattern(2)),
+            PieceType::Warlock => Pattern::new(PatternEnum::get_magician()),
 
             // Portal Units
             PieceType::Portal => Pattern::new_null(),
@@ -261,7 +270,7 @@ impl PieceType {
             ]),
             PieceType::Gargoyle => Pattern::new(PatternEnum::CirclePattern(5)),
             PieceType::Golem => Pattern::new_many(vec![
-                PatternEnum::DirectionalPattern(2),
+                PatternEnum::get_magician(),
                 PatternEnum::SubdirectionalPattern(1),
             ]),
             PieceType::Imp => Pattern::new(PatternEnum::KnightPattern(2, 1)),
@@ -270,9 +279,9 @@ impl PieceType {
                 PatternEnum::KnightPattern(2, 1),
                 PatternEnum::SubdirectionalPattern(1),
             ]),
-            PieceType::Necromancer => Pattern::new(PatternEnum::DirectionalPattern(2)),
+            PieceType::Necromancer => Pattern::new(PatternEnum::get_magician()),
             PieceType::Ogre => Pattern::new_many(vec![
-                PatternEnum::DirectionalPattern(2),
+                PatternEnum::get_magician(),
                 PatternEnum::SubdirectionalPattern(1),
             ]),
             PieceType::Oni => Pattern::new_many(vec![
@@ -293,10 +302,11 @@ impl PieceType {
         }
     }
 
-    pub fn get_take_pattern(&self) -> Pattern {
+    /// forward is only used by pawn like pieces
+    pub fn get_take_pattern(&self, forward: Direction) -> Pattern {
         match self {
             // Classic Pieces
-            PieceType::Pawn => Pattern::new(PatternEnum::PawnTakePattern(Direction::Up)),
+            PieceType::Pawn => Pattern::new(PatternEnum::PawnTakePattern(forward)),
             PieceType::Bishop => Pattern::new(PatternEnum::DiagonalPattern(-1)),
             PieceType::Knight => Pattern::new(PatternEnum::KnightPattern(2, 1)),
             PieceType::Rook => Pattern::new(PatternEnum::DirectionalPattern(-1)),
@@ -321,9 +331,9 @@ impl PieceType {
                 PatternEnum::DiagonalPattern(-1),
             ]),
             PieceType::Ram => Pattern::new_null(),
-            PieceType::ShieldBearer => Pattern::new(PatternEnum::PawnTakePattern(Direction::Up)),
+            PieceType::ShieldBearer => Pattern::new(PatternEnum::PawnTakePattern(forward)),
             PieceType::Ship => Pattern::new(PatternEnum::SubdirectionalPattern(1)),
-            PieceType::SuperPawn => Pattern::new(PatternEnum::SuperPawnTakePattern(Direction::Up)),
+            PieceType::SuperPawn => Pattern::new(PatternEnum::SuperPawnTakePattern(forward)),
             PieceType::TeslaTower => Pattern::new(PatternEnum::DirectionalPattern(1)),
             PieceType::Wall => Pattern::new_null(),
             PieceType::Warlock => Pattern::new_null(),
@@ -337,7 +347,7 @@ impl PieceType {
             ]),
             PieceType::Gargoyle => Pattern::new(PatternEnum::CirclePattern(5)),
             PieceType::Golem => Pattern::new_many(vec![
-                PatternEnum::DirectionalPattern(2),
+                PatternEnum::get_magician(),
                 PatternEnum::SubdirectionalPattern(1),
             ]),
             PieceType::Imp => Pattern::new_null(),
@@ -348,7 +358,7 @@ impl PieceType {
             ]),
             PieceType::Necromancer => Pattern::new_null(),
             PieceType::Ogre => Pattern::new_many(vec![
-                PatternEnum::DirectionalPattern(2),
+                PatternEnum::get_magician(),
                 PatternEnum::SubdirectionalPattern(1),
             ]),
             PieceType::Oni => Pattern::new_many(vec![
@@ -381,7 +391,7 @@ impl PieceType {
 
             // Starting Pieces
             PieceType::Archer => Pattern::new(PatternEnum::CirclePattern(4)),
-            PieceType::Balista => Pattern::new(PatternEnum::DirectionalPattern(6)),
+            PieceType::Balista => Pattern::new(PatternEnum::get_balista_attack()),
             PieceType::Builder => Pattern::new_null(),
             PieceType::Cannon => Pattern::new(PatternEnum::SquarePattern(3)),
             PieceType::Catapult => Pattern::new_null(),
@@ -421,7 +431,8 @@ impl PieceType {
         }
     }
 
-    pub fn get_ability_data(&self) -> AbilityData {
+    /// forward is only used by the pawn ability
+    pub fn get_ability_data(&self, forward: Direction) -> AbilityData {
         match self {
             // Classic Pieces
             PieceType::Pawn => AbilityData::new(
@@ -429,51 +440,54 @@ impl PieceType {
                 0,
                 1,
                 0,
-                Pattern::new(PatternEnum::PawnAbilityPattern(Direction::Up)),
+                Pattern::new(PatternEnum::PawnAbilityPattern(forward)),
             ),
-            PieceType::Bishop => AbilityData::new(1, 0, 0, 2, Pattern::new_null()),
-            PieceType::Knight => AbilityData::new(1, 1, 1, 10, Pattern::new_null()),
-            PieceType::Rook => AbilityData::new(2, 0, 0, 10, Pattern::new_null()),
-            PieceType::Queen => AbilityData::new(1, 0, 0, 2, Pattern::new_null()),
-            PieceType::King => AbilityData::new(0, 0, 0, 1, Pattern::new_null()),
+            PieceType::Bishop => AbilityData::new(0, 2, 1, 0, Pattern::new_null()),
+            PieceType::Knight => AbilityData::new(1, 10, 1, 0, Pattern::new_null()),
+            PieceType::Rook => AbilityData::new(0, 10, 2, 0, Pattern::new_null()),
+            PieceType::Queen => AbilityData::new(0, 2, 1, 0, Pattern::new_null()),
+            // once per game
+            PieceType::King => AbilityData::new(0, u32::MAX, 0, 0, Pattern::new_null()),
 
             // Starting Pieces
             PieceType::Archer => AbilityData::new(0, 0, 0, 0, Pattern::new_null()),
             PieceType::Balista => AbilityData::new(0, 0, 0, 0, Pattern::new_null()),
-            PieceType::Builder => AbilityData::new(1, 0, 0, 10, Pattern::new_null()),
+            PieceType::Builder => AbilityData::new(0, 10, 1, 0, Pattern::new_null()),
             PieceType::Cannon => AbilityData::new(0, 0, 0, 0, Pattern::new_null()),
-            PieceType::Catapult => AbilityData::new(1, 0, 0, 4, Pattern::new_null()),
-            PieceType::CrazyPawn => AbilityData::new(1, 0, 0, 0, Pattern::new_null()),
-            PieceType::Magician => AbilityData::new(1, 2, 1, 6, Pattern::new_null()),
-            PieceType::Paladin => AbilityData::new(1, 2, 1, 4, Pattern::new_null()),
-            PieceType::Ram => AbilityData::new(1, 0, 0, 4, Pattern::new_null()),
-            PieceType::ShieldBearer => AbilityData::new(1, 0, 0, 15, Pattern::new_null()),
-            PieceType::Ship => AbilityData::new(1, 0, 0, 12, Pattern::new_null()),
-            PieceType::SuperPawn => AbilityData::new(1, 0, 0, 10, Pattern::new_null()),
-            PieceType::TeslaTower => AbilityData::new(2, 0, 0, 10, Pattern::new_null()),
+            PieceType::Catapult => AbilityData::new(0, 4, 1, 0, Pattern::new_null()),
+            PieceType::CrazyPawn => AbilityData::new(0, 0, 1, 0, Pattern::new_null()),
+            PieceType::Magician => AbilityData::new(2, 6, 1, 0, Pattern::new_null()),
+            PieceType::Paladin => AbilityData::new(2, 4, 1, 0, Pattern::new_null()),
+            PieceType::Ram => AbilityData::new(0, 4, 1, 1, Pattern::new_null()),
+            PieceType::ShieldBearer => AbilityData::new(0, 15, 1, 0, Pattern::new_null()),
+            PieceType::Ship => AbilityData::new(0, 12, 1, 0, Pattern::new_null()),
+            PieceType::SuperPawn => AbilityData::new(0, 10, 1, 0, Pattern::new_null()),
+            PieceType::TeslaTower => AbilityData::new(0, 10, 2, 1, Pattern::new_null()),
             PieceType::Wall => AbilityData::new(0, 0, 0, 0, Pattern::new_null()),
-            PieceType::Warlock => AbilityData::new(1, 3, 1, 5, Pattern::new_null()),
+            PieceType::Warlock => AbilityData::new(3, 5, 1, 3, Pattern::new_null()),
 
             // Portal Units
-            PieceType::Portal => AbilityData::new(1, 0, 0, 0, Pattern::new_null()),
-            PieceType::Basilisk => AbilityData::new(1, 0, 0, 20, Pattern::new_null()),
-            PieceType::Dragon => AbilityData::new(2, 1, 0, 2, Pattern::new_null()),
+            PieceType::Portal => AbilityData::new(0, 0, 1, 0, Pattern::new_null()),
+            PieceType::Basilisk => AbilityData::new(0, 20, 1, 0, Pattern::new_null()),
+            PieceType::Dragon => AbilityData::new(1, 2, 2, 0, Pattern::new_null()),
             PieceType::Gargoyle => AbilityData::new(0, 0, 0, 0, Pattern::new_null()),
             PieceType::Golem => AbilityData::new(0, 0, 0, 0, Pattern::new_null()),
-            PieceType::Imp => AbilityData::new(1, 2, 0, 5, Pattern::new_null()),
-            PieceType::Mandragora => AbilityData::new(1, 0, 0, 10, Pattern::new_null()),
-            PieceType::Mermaid => AbilityData::new(1, 2, 0, 5, Pattern::new_null()),
-            PieceType::Necromancer => AbilityData::new(2, 1, 0, 4, Pattern::new_null()),
-            PieceType::Ogre => AbilityData::new(1, 0, 0, 4, Pattern::new_null()),
-            PieceType::Oni => AbilityData::new(1, 1, 0, 7, Pattern::new_null()),
-            PieceType::Spider => AbilityData::new(1, 1, 0, 12, Pattern::new_null()),
-            PieceType::SpiderEgg => AbilityData::new(0, 0, 0, 0, Pattern::new_null()),
-            PieceType::Succubus => AbilityData::new(1, 1, 0, 10, Pattern::new_null()),
-            PieceType::Witch => AbilityData::new(1, 2, 0, 8, Pattern::new_null()),
+            PieceType::Imp => AbilityData::new(2, 5, 1, 0, Pattern::new_null()),
+            PieceType::Mandragora => AbilityData::new(0, 10, 1, 0, Pattern::new_null()),
+            PieceType::Mermaid => AbilityData::new(2, 5, 1, 0, Pattern::new_null()),
+            PieceType::Necromancer => AbilityData::new(1, 4, 2, 0, Pattern::new_null()),
+            PieceType::Ogre => AbilityData::new(0, 4, 1, 0, Pattern::new_null()),
+            PieceType::Oni => AbilityData::new(1, 7, 1, 0, Pattern::new_null()),
+            PieceType::Spider => AbilityData::new(1, 12, 1, 0, Pattern::new_null()),
+            PieceType::SpiderEgg => {
+                AbilityData::new(0, 0, 0, 5, Pattern::new_null()).with_autocast()
+            }
+            PieceType::Succubus => AbilityData::new(1, 10, 1, 0, Pattern::new_null()),
+            PieceType::Witch => AbilityData::new(2, 8, 1, 2, Pattern::new_null()),
 
             // Other Units
-            PieceType::Swamp => AbilityData::new(1, 0, 0, 5, Pattern::new_null()),
-            PieceType::Leech => AbilityData::new(1, 2, 0, 8, Pattern::new_null()),
+            PieceType::Swamp => AbilityData::new(0, 5, 1, 0, Pattern::new_null()),
+            PieceType::Leech => AbilityData::new(2, 8, 1, 2, Pattern::new_null()),
         }
     }
 }
@@ -492,10 +506,17 @@ pub struct Piece {
     pub take_pattern: Pattern,
     pub attack_pattern: Pattern,
     pub ability_data: AbilityData,
+    pub ability_ready_at: ChessTime,
+    pub cast: Option<Cast>,
 }
 
 impl Piece {
+    /// Uses the default forward of the color, see Board::new_piece for the board one
     pub fn new(id: u32, color: Color, piece_type: PieceType) -> Self {
+        Self::new_facing(id, color, piece_type, color.default_forward())
+    }
+
+    pub fn new_facing(id: u32, color: Color, piece_type: PieceType, forward: Direction) -> Self {
         Self {
             id,
             color,
@@ -505,10 +526,32 @@ impl Piece {
             properties: piece_type.get_properties(),
             effects: Vec::new(),
             tags: piece_type.get_tags(),
-            move_pattern: piece_type.get_move_pattern(),
-            take_pattern: piece_type.get_take_pattern(),
+            move_pattern: piece_type.get_move_pattern(forward),
+            take_pattern: piece_type.get_take_pattern(forward),
             attack_pattern: piece_type.get_attack_pattern(),
-            ability_data: piece_type.get_ability_data(),
+            ability_data: piece_type.get_ability_data(forward),
+            ability_ready_at: ChessTime::new(),
+            cast: None,
+        }
+    }
+
+    // ability stuff
+    /// Not casting and the cooldown already elapsed
+    pub fn ability_ready(&self, board: &Board) -> bool {
+        self.cast.is_none() && board.time >= self.ability_ready_at
+    }
+
+    /// The ability being cast, the piece cannot do anything else meanwhile
+    pub fn casting(&self) -> Option<&Cast> {
+        self.cast.as_ref()
+    }
+
+    pub fn get_pattern(&self, action: &BasicAction) -> &Pattern {
+        match action {
+            BasicAction::Move => &self.move_pattern,
+            BasicAction::Take => &self.take_pattern,
+            BasicAction::Attack => &self.attack_pattern,
+            BasicAction::Ability => &self.ability_data.pattern,
         }
     }
 
@@ -545,6 +588,10 @@ impl Piece {
 
     /// self is not mutable to avoid double mutation conflicts with the board
     pub fn on_action_received(&self, action: &PieceAction, board: &mut Board) {
+        // DEAD units skip every other on death hook
+        if self.is_dead() && matches!(action, PieceAction::Die(_)) {
+            return;
+        }
         self.effects
             .iter()
             .for_each(|e| e.on_action_received(board, self));
@@ -553,6 +600,16 @@ impl Piece {
             .for_each(|t| t.on_action_received(board, self, action));
     }
 
+    /// self is not mutable to avoid double mutation conflicts with the board
+    pub fn on_board_action(&self, action: &BoardAction, board: &mut Board) {
+        self.effects
+            .iter()
+            .for_each(|e| e.on_board_action(board, self, action));
+        self.tags
+            .iter()
+            .for_each(|t| t.on_board_action(board, self, action));
+    }
+
     /// self is not mutable to avoid double mutation conflicts with the board
     pub fn on_tick(&self, board: &mut Board) {
         self.effects.iter().for_each(|e| e.on_tick(board, self));
@@ -563,6 +620,78 @@ impl Piece {
         self.effects.iter().for_each(|e| e.on_expire(board, self));
     }
 
+    // property stuff
+    pub fn life(&self) -> u32 {
+        self.properties
+            .iter()
+            .find_map(|p| match p {
+                Property::Life(life) => Some(*life),
+                _ => None,
+            })
+            .unwrap_or(1)
+    }
+
+    pub fn current_life(&self) -> u32 {
+        self.properties
+            .iter()
+            .find_map(|p| match p {
+                Property::CurrentLife(life) => Some(*life),
+                _ => None,
+            })
+            .unwrap_or_else(|| self.life())
+    }
+
+    pub fn attack_damage(&self) -> u32 {
+        self.properties
+            .iter()
+            .find_map(|p| match p {
+                Property::AttackDamage(damage) => Some(*damage),
+                _ => None,
+            })
+            .unwrap_or(1)
+    }
+
+    pub fn take_damage(&self) -> u32 {
+        self.properties
+            .iter()
+            .find_map(|p| match p {
+                Property::TakeDamage(damage) => Some(*damage),
+                _ => None,
+            })
+            .unwrap_or(1)
+    }
+
+    pub fn piece_list(&self) -> &[Piece] {
+        self.properties
+            .iter()
+            .find_map(|p| match p {
+                Property::PieceList(list) => Some(list.as_slice()),
+                _ => None,
+            })
+            .unwrap_or(&[])
+    }
+
+    pub fn add_to_piece_list(&mut self, piece: Piece) {
+        match self.properties.iter_mut().find_map(|p| match p {
+            Property::PieceList(list) => Some(list),
+            _ => None,
+        }) {
+            Some(list) => list.push(piece),
+            None => self.properties.push(Property::PieceList(vec![piece])),
+        }
+    }
+
+    pub fn set_current_life(&mut self, life: u32) {
+        match self
+            .properties
+            .iter_mut()
+            .find(|p| matches!(p, Property::CurrentLife(_)))
+        {
+            Some(property) => *property = Property::CurrentLife(life),
+            None => self.properties.push(Property::CurrentLife(life)),
+        }
+    }
+
     // tag stuff
     pub fn is_bio(&self) -> bool {
         self.tags.contains(&Tag::Biologic)
@@ -8,10 +8,29 @@ pub enum Color {
     White,
 }
 
+impl Color {
+    /// Players take their turns from the lowest to the highest order
+    pub fn turn_order(&self) -> u32 {
+        match self {
+            Color::White => 0,
+            Color::Black => 1,
+        }
+    }
+
+    /// Used when the board has no direction for the player
+    pub fn default_forward(&self) -> Direction {
+        match self {
+            Color::White => Direction::Up,
+            Color::Black => Direction::Down,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Player {
     pub id: u32,
     pub color: Color,
+    pub forward: Option<Direction>, // None uses the board directions, see Board::forward
     pub max_movements: u32,
     pub movements: u32,
     pub max_mana: u32,
@@ -29,6 +48,7 @@ impl Player {
         Self {
             id,
             color: Color::Black,
+            forward: None,
             max_movements: 1,
             movements: 1,
             max_mana: 2,
@@ -62,6 +82,10 @@ impl Player {
         self.mana >= cost
     }
 
+    pub fn can_use_movements(&self, cost: u32) -> bool {
+        self.movements >= cost
+    }
+
     pub fn use_movement(&mut self) -> bool {
         if self.can_move() {
             self.movements -= 1;
@@ -79,4 +103,55 @@ impl Player {
             false
         }
     }
+
+    pub fn use_movements(&mut self, cost: u32) -> bool {
+        if self.can_use_movements(cost) {
+            self.movements -= cost;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Raises the max mana, used by temporary modifiers
+    pub fn add_max_mana(&mut self, amount: u32) {
+        self.max_mana += amount;
+    }
+
+    /// Lowers the max mana, the mana above it is lost
+    pub fn remove_max_mana(&mut self, amount: u32) {
+        self.max_mana = self.max_mana.saturating_sub(amount);
+        self.mana = self.mana.min(self.max_mana);
+    }
+
+    // deck stuff
+    /// Moves the top card of the personal deck to the hand, returns its id
+    pub fn draw_card(&mut self) -> Option<u32> {
+        let card = self.current_deck.draw()?;
+        let id = card.id;
+        self.current_hand.add_card(card);
+        Some(id)
+    }
+
+    pub fn get_deck(&self, deck_id: u32) -> Option<&Deck> {
+        [
+            &self.current_hand,
+            &self.current_deck,
+            &self.central_deck,
+            &self.discard_pile,
+        ]
+        .into_iter()
+        .find(|deck| deck.id == deck_id)
+    }
+
+    pub fn get_deck_mut(&mut self, deck_id: u32) -> Option<&mut Deck> {
+        [
+            &mut self.current_hand,
+            &mut self.current_deck,
+            &mut self.central_deck,
+            &mut self.discard_pile,
+        ]
+        .into_iter()
+        .find(|deck| deck.id == deck_id)
+    }
 }
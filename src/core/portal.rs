@@ -0,0 +1,169 @@
+use crate::prelude::*;
+
+/// Cost of summoning a unit from a portal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SummonRequirement {
+    pub rounds: u32,
+    pub mana: u32,
+    pub portals: u32, // owned portals needed on the board
+}
+
+impl SummonRequirement {
+    pub fn new(rounds: u32, mana: u32, portals: u32) -> Self {
+        Self {
+            rounds,
+            mana,
+            portals,
+        }
+    }
+}
+
+/// Every unit a portal can summon
+pub const PORTAL_UNITS: [PieceType; 13] = [
+    PieceType::Basilisk,
+    PieceType::Dragon,
+    PieceType::Gargoyle,
+    PieceType::Golem,
+    PieceType::Imp,
+    PieceType::Mandragora,
+    PieceType::Mermaid,
+    PieceType::Necromancer,
+    PieceType::Ogre,
+    PieceType::Oni,
+    PieceType::Spider,
+    PieceType::Succubus,
+    PieceType::Witch,
+];
+
+impl PieceType {
+    /// None if the portal cannot summon it
+    pub fn get_summon_requirement(&self) -> Option<SummonRequirement> {
+        match self {
+            PieceType::Basilisk => Some(SummonRequirement::new(5, 1, 1)),
+            PieceType::Dragon => Some(SummonRequirement::new(15, 3, 2)),
+            PieceType::Gargoyle => Some(SummonRequirement::new(5, 1, 1)),
+            PieceType::Golem => Some(SummonRequirement::new(5, 0, 1)),
+            PieceType::Imp => Some(SummonRequirement::new(5, 2, 1)),
+            PieceType::Mandragora => Some(SummonRequirement::new(5, 2, 1)),
+            PieceType::Mermaid => Some(SummonRequirement::new(3, 0, 2)),
+            PieceType::Necromancer => Some(SummonRequirement::new(7, 2, 1)),
+            PieceType::Ogre => Some(SummonRequirement::new(4, 0, 1)),
+            PieceType::Oni => Some(SummonRequirement::new(3, 1, 2)),
+            PieceType::Spider => Some(SummonRequirement::new(5, 0, 1)),
+            PieceType::Succubus => Some(SummonRequirement::new(12, 1, 1)),
+            PieceType::Witch => Some(SummonRequirement::new(12, 1, 1)),
+            _ => None,
+        }
+    }
+}
+
+impl Board {
+    pub fn count_portals(&self, color: Color) -> u32 {
+        self.find_pieces_of_type(color, PieceType::Portal).len() as u32
+    }
+
+    /// Portals can only be built on empty magic squares
+    pub fn can_build_portal(&self, pos: Position) -> bool {
+        self.check_build_portal(pos).is_ok()
+    }
+
+    // Warlock
+    pub(crate) fn check_build_portal(&self, pos: Position) -> Result<(), RuleError> {
+        let square = self.get_square(pos).ok_or(RuleError::OutOfBoard)?;
+        if square.piece.is_some() {
+            Err(RuleError::Occupied)
+        } else if !square.magic {
+            Err(RuleError::NotMagic)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Creates the portal at the end of the cast and refunds 2 mana to the warlock owner
+    pub(crate) fn build_portal(
+        &mut self,
+        pos: Position,
+        warlock: &Piece,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        self.check_build_portal(pos)?;
+        let id = self.spawn_piece(pos, warlock.color, PieceType::Portal)?;
+        outcome.spawned.push(id);
+        self.add_mana_to_color(warlock.color, 2);
+        Ok(())
+    }
+
+    // Portal
+    pub(crate) fn check_portal_summon(
+        &self,
+        pos: Position,
+        portal: &Piece,
+        unit: PieceType,
+        subdirection: SubDirection,
+    ) -> Result<(), RuleError> {
+        let requirement = unit
+            .get_summon_requirement()
+            .ok_or(RuleError::InvalidTarget)?;
+        if self.count_portals(portal.color) < requirement.portals {
+            return Err(RuleError::NotEnoughPortals);
+        }
+        let player = self
+            .get_player_of_color(portal.color)
+            .ok_or(RuleError::NoPlayer)?;
+        // the mana cost of the portal ability is the one of the unit
+        if !player.can_use_mana(requirement.mana) {
+            return Err(RuleError::NoMana);
+        }
+        match self.get_square(pos + subdirection) {
+            None => Err(RuleError::OutOfBoard),
+            Some(square) if square.piece.is_some() => Err(RuleError::Occupied),
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// Pays the unit mana and queues its arrival once its rounds elapse
+    pub(crate) fn summon_from_portal(
+        &mut self,
+        pos: Position,
+        portal: &Piece,
+        unit: PieceType,
+        subdirection: SubDirection,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        self.check_portal_summon(pos, portal, unit, subdirection)?;
+        let requirement = unit
+            .get_summon_requirement()
+            .ok_or(RuleError::InvalidTarget)?;
+        let player = self
+            .get_player_of_color_mut(portal.color)
+            .ok_or(RuleError::NoPlayer)?;
+        player.use_mana(requirement.mana);
+        let player_id = player.id;
+        outcome.mana_spent += requirement.mana;
+
+        let piece = self.new_piece(portal.color, unit);
+        self.schedule_piece_event(
+            portal.id,
+            player_id,
+            ChessTime::from_rounds(requirement.rounds),
+            EventFunctionEnum::Summon(pos + subdirection, piece),
+        );
+        Ok(())
+    }
+
+    /// Own portals in the mermaid pattern get their casts and summons some rounds closer
+    pub(crate) fn hasten_portals(&mut self, pos: Position, mermaid: &Piece, rounds: u32) {
+        let pattern = Pattern::new(PatternEnum::get_mermaid());
+        let portals: Vec<u32> = self
+            .get_squares_with_action(pos, &pattern, BasicAction::Ability)
+            .into_iter()
+            .filter_map(|square| square.piece.as_ref())
+            .filter(|piece| piece.color == mermaid.color && piece.piece_type == PieceType::Portal)
+            .map(|piece| piece.id)
+            .collect();
+        for id in portals {
+            self.hasten_cast(id, rounds);
+            self.hasten_events_of(id, rounds);
+        }
+    }
+}
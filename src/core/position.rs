@@ -48,6 +48,15 @@ pub enum Direction {
 }
 
 impl Direction {
+    pub fn all() -> [Direction; 4] {
+        [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ]
+    }
+
     pub fn axis(&self) -> Axis {
         match self {
             Direction::Up | Direction::Down => Axis::Y,
@@ -96,6 +105,19 @@ pub enum SubDirection {
 }
 
 impl SubDirection {
+    pub fn all() -> [SubDirection; 8] {
+        [
+            SubDirection::Up,
+            SubDirection::UpRight,
+            SubDirection::Right,
+            SubDirection::DownRight,
+            SubDirection::Down,
+            SubDirection::DownLeft,
+            SubDirection::Left,
+            SubDirection::UpLeft,
+        ]
+    }
+
     pub fn opposite(&self) -> SubDirection {
         match self {
             SubDirection::Up => SubDirection::Down,
@@ -178,6 +200,14 @@ impl Position {
         }
     }
 
+    /// Unit step towards the same signs, (3, -2) is (1, -1)
+    pub fn signum(&self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
     pub fn length_sqr(&self) -> i32 {
         self.x * self.x + self.y * self.y
     }
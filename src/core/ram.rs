@@ -0,0 +1,167 @@
+use crate::prelude::*;
+
+/// One step of a ram charge, in order, for animations and tests
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RamStep {
+    Move(FromToAction),
+    Hit {
+        piece_id: u32,
+        damage: u32,
+        killed: bool,
+    },
+    // hit an IMP unit, both take 1 hp and the charge ends
+    Impact {
+        piece_id: u32,
+        killed: bool,
+        ram_killed: bool,
+    },
+    // the unit cannot receive abilities, the charge ends
+    Blocked {
+        piece_id: u32,
+    },
+    // the charge reached the end of the board
+    Border,
+    // no depth left
+    Stop,
+}
+
+impl Board {
+    /// Charges in the direction until it meets a unit and takes it. Then it keeps ramming
+    /// with a depth of distance_traveled / 5, every square moved or hp taken costs one depth.
+    pub fn ram(&mut self, piece_id: u32, direction: Direction) -> Result<Vec<RamStep>, RuleError> {
+        let (mut pos, ram) = self.find_piece(piece_id).ok_or(RuleError::NoPiece)?;
+        let damage = ram.take_damage();
+        let mut trace = Vec::new();
+
+        // charge
+        let mut traveled = 0;
+        while self
+            .get_square(pos + direction)
+            .is_some_and(|square| square.piece.is_none())
+        {
+            pos = self.ram_step(piece_id, pos, direction, &mut trace);
+            traveled += 1;
+        }
+
+        // the first unit is taken, the ram only moves in if it dies
+        let mut depth = traveled / 5;
+        match self.ram_hit(piece_id, pos + direction, damage, &mut trace) {
+            Some(true) => {
+                pos = self.ram_step(piece_id, pos, direction, &mut trace);
+            }
+            Some(false) => {}
+            None => return Ok(trace),
+        }
+
+        // ramming behind, one hp at a time
+        while depth > 0 {
+            let next = pos + direction;
+            match self.get_square(next) {
+                None => {
+                    trace.push(RamStep::Border);
+                    return Ok(trace);
+                }
+                Some(square) if square.piece.is_none() => {
+                    pos = self.ram_step(piece_id, pos, direction, &mut trace);
+                }
+                Some(_) => {
+                    if self.ram_hit(piece_id, next, 1, &mut trace).is_none() {
+                        return Ok(trace);
+                    }
+                }
+            }
+            depth -= 1;
+        }
+        trace.push(RamStep::Stop);
+        Ok(trace)
+    }
+
+    fn ram_step(
+        &mut self,
+        piece_id: u32,
+        from: Position,
+        direction: Direction,
+        trace: &mut Vec<RamStep>,
+    ) -> Position {
+        let to = from + direction;
+        if self.move_piece(from, to).is_err() {
+            return from;
+        }
+        trace.push(RamStep::Move(FromToAction { from, to, piece_id }));
+        to
+    }
+
+    /// Damages the unit at pos. Some(killed) if the charge can go on, None if it ended.
+    fn ram_hit(
+        &mut self,
+        piece_id: u32,
+        pos: Position,
+        damage: u32,
+        trace: &mut Vec<RamStep>,
+    ) -> Option<bool> {
+        let Some(square) = self.get_square(pos) else {
+            trace.push(RamStep::Border);
+            return None;
+        };
+        let target = square.piece.clone()?;
+
+        if target.is_imp() {
+            let killed = self.damage_piece(target.id, 1, Some(piece_id));
+            let ram_killed = self.damage_piece(piece_id, 1, Some(target.id));
+            trace.push(RamStep::Impact {
+                piece_id: target.id,
+                killed,
+                ram_killed,
+            });
+            return None;
+        }
+        if !target.can_receive_action(&BasicAction::Ability, self) {
+            trace.push(RamStep::Blocked {
+                piece_id: target.id,
+            });
+            return None;
+        }
+
+        let killed = self.damage_piece(target.id, damage, Some(piece_id));
+        trace.push(RamStep::Hit {
+            piece_id: target.id,
+            damage,
+            killed,
+        });
+        Some(killed)
+    }
+
+    /// Adds what the charge did to the outcome of the ability
+    pub(crate) fn record_ram(ram_id: u32, trace: &[RamStep], outcome: &mut Outcome) {
+        for step in trace {
+            match step {
+                RamStep::Move(from_to) => outcome.moved.push(from_to.clone()),
+                RamStep::Hit {
+                    piece_id,
+                    damage,
+                    killed,
+                } => {
+                    outcome.damaged.push((*piece_id, *damage));
+                    if *killed {
+                        outcome.killed.push(*piece_id);
+                    }
+                }
+                RamStep::Impact {
+                    piece_id,
+                    killed,
+                    ram_killed,
+                } => {
+                    outcome.damaged.push((*piece_id, 1));
+                    outcome.damaged.push((ram_id, 1));
+                    if *killed {
+                        outcome.killed.push(*piece_id);
+                    }
+                    if *ram_killed {
+                        outcome.killed.push(ram_id);
+                    }
+                }
+                RamStep::Blocked { .. } | RamStep::Border | RamStep::Stop => {}
+            }
+        }
+    }
+}
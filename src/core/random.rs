@@ -0,0 +1,96 @@
+use crate::prelude::*;
+
+/// Deterministic rng of the board, a new value is rolled on every movement, turn and round.
+/// Same seed and same actions always give the same game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChessRandom {
+    pub seed: u64,
+    state: u64,
+    pub round_rng: u64,
+    pub turn_rng: u64,
+    pub movement_rng: u64,
+}
+
+impl Default for ChessRandom {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl ChessRandom {
+    pub fn new(seed: u64) -> Self {
+        let mut rng = Self {
+            seed,
+            state: seed,
+            round_rng: 0,
+            turn_rng: 0,
+            movement_rng: 0,
+        };
+        rng.on_round();
+        rng
+    }
+
+    /// splitmix64, small and good enough for a board game
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Number between 0 (inclusive) and n (exclusive), 0 if n is 0
+    pub fn next_below(&mut self, n: u64) -> u64 {
+        if n == 0 { 0 } else { self.next_u64() % n }
+    }
+
+    pub fn on_movement(&mut self) {
+        self.movement_rng = self.next_u64();
+    }
+
+    pub fn on_turn(&mut self) {
+        self.turn_rng = self.next_u64();
+        self.on_movement();
+    }
+
+    pub fn on_round(&mut self) {
+        self.round_rng = self.next_u64();
+        self.on_turn();
+    }
+
+    /// Maps a rolled value to a number between 0 and 1
+    pub fn to_unit(value: u64) -> f64 {
+        (value >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn to_direction(value: u64) -> Direction {
+        match value % 4 {
+            0 => Direction::Up,
+            1 => Direction::Right,
+            2 => Direction::Down,
+            _ => Direction::Left,
+        }
+    }
+
+    pub fn to_subdirection(value: u64) -> SubDirection {
+        match value % 8 {
+            0 => SubDirection::Up,
+            1 => SubDirection::UpRight,
+            2 => SubDirection::Right,
+            3 => SubDirection::DownRight,
+            4 => SubDirection::Down,
+            5 => SubDirection::DownLeft,
+            6 => SubDirection::Left,
+            _ => SubDirection::UpLeft,
+        }
+    }
+
+    pub fn to_diagonal(value: u64) -> SubDirection {
+        match value % 4 {
+            0 => SubDirection::UpRight,
+            1 => SubDirection::DownRight,
+            2 => SubDirection::DownLeft,
+            _ => SubDirection::UpLeft,
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::prelude::*;
+
+impl Board {
+    /// Raises the max mana of the player until the duration elapses, returns the event id
+    pub fn add_temporary_max_mana(
+        &mut self,
+        player_id: u32,
+        amount: u32,
+        duration: ChessTime,
+    ) -> u32 {
+        if let Some(player) = self.get_player_mut(player_id) {
+            player.add_max_mana(amount);
+        }
+        self.schedule_event(
+            player_id,
+            duration,
+            EventFunctionEnum::RemoveMaxMana(amount),
+        )
+    }
+
+    // Mandragora
+    /// +1 mana to spend now, and +1 max mana for this turn and the next turn of the owner
+    pub(crate) fn mandragora_mana(&mut self, mandragora: &Piece) -> Result<(), RuleError> {
+        let player_id = self
+            .get_player_of_color(mandragora.color)
+            .ok_or(RuleError::NoPlayer)?
+            .id;
+        // it expires when the turn after the owner next turn starts
+        let turns = self.players.len() as u32 + 1;
+        self.add_temporary_max_mana(player_id, 1, ChessTime::from_turns(turns));
+        self.add_mana_to_color(mandragora.color, 1);
+        Ok(())
+    }
+
+    // Imp
+    pub(crate) fn check_imp_draw(
+        &self,
+        imp: &Piece,
+        target: &AbilityTarget,
+    ) -> Result<(), RuleError> {
+        let player = self
+            .get_player_of_color(imp.color)
+            .ok_or(RuleError::NoPlayer)?;
+        let has_card = match target {
+            AbilityTarget::None => !player.current_deck.cards.is_empty(),
+            AbilityTarget::Card(card_id) => player.central_deck.has_card(*card_id),
+            _ => return Err(RuleError::InvalidTarget),
+        };
+        if has_card {
+            Ok(())
+        } else {
+            Err(RuleError::NoCard)
+        }
+    }
+
+    /// Draws from the personal deck, or moves the central card into the personal deck
+    /// and shuffles it with the board rng
+    pub(crate) fn imp_draw(
+        &mut self,
+        imp: &Piece,
+        target: &AbilityTarget,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        self.check_imp_draw(imp, target)?;
+        let player = self
+            .players
+            .iter_mut()
+            .find(|player| player.color == imp.color)
+            .ok_or(RuleError::NoPlayer)?;
+        match target {
+            AbilityTarget::Card(card_id) => {
+                let card = player
+                    .central_deck
+                    .take_card(*card_id)
+                    .ok_or(RuleError::NoCard)?;
+                player.current_deck.add_card(card);
+                player.current_deck.shuffle(&mut self.rng);
+            }
+            _ => {
+                let card_id = player.draw_card().ok_or(RuleError::NoCard)?;
+                outcome.drawn.push(card_id);
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn imp_targets(&self, imp: &Piece) -> Vec<AbilityTarget> {
+        let central = self
+            .get_player_of_color(imp.color)
+            .map(|player| player.central_deck.cards.as_slice())
+            .unwrap_or(&[]);
+        std::iter::once(AbilityTarget::None)
+            .chain(central.iter().map(|card| AbilityTarget::Card(card.id)))
+            .collect()
+    }
+
+    // CrazyPawn
+    /// Dies and its owner draws 2 cards, as many as the personal deck has
+    pub(crate) fn crazy_pawn_draw(
+        &mut self,
+        crazy_pawn: &Piece,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        self.kill_piece(crazy_pawn.id).ok_or(RuleError::NoPiece)?;
+        outcome.killed.push(crazy_pawn.id);
+        let player = self
+            .get_player_of_color_mut(crazy_pawn.color)
+            .ok_or(RuleError::NoPlayer)?;
+        let drawn: Vec<u32> = (0..2).filter_map(|_| player.draw_card()).collect();
+        outcome.drawn.extend(drawn);
+        Ok(())
+    }
+}
@@ -0,0 +1,377 @@
+use crate::prelude::*;
+
+/// Why the board refused an action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleError {
+    NotExecutable, // Die, Kill, effect, board and other actions are fired by the board itself
+    NoPlayer,
+    NotYourTurn,
+    NoPiece,
+    NoTarget,
+    FriendlyTarget,
+    Occupied,
+    OutOfBoard,
+    NotMagic, // portals are only built on magic squares
+    NotEnoughPortals,
+    OutOfPattern,
+    NoMovements,
+    NoMana,
+    NoCard,
+    NoDeck,
+    InvalidTarget,
+    AlreadyMoved, // the piece already acted this turn or an effect locks it
+    Casting,      // the piece is busy casting its ability
+    OnCooldown,
+    ActionVetoed,  // a tag or effect of the acting piece forbids it
+    ReceiveVetoed, // a tag or effect of the target forbids it
+}
+
+/// What an applied action did to the board
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Outcome {
+    pub moved: Vec<FromToAction>,
+    pub damaged: Vec<(u32, u32)>, // (piece_id, damage)
+    pub killed: Vec<u32>,
+    pub spawned: Vec<u32>,
+    pub transformed: Vec<(u32, PieceType)>, // (piece_id, new type)
+    pub affected: Vec<(u32, Effect)>,       // (piece_id, applied effect)
+    pub drawn: Vec<u32>,                    // card ids
+    pub tagged: Vec<(u32, Tag)>,            // (piece_id, added tag)
+    pub mana_spent: u32,
+    pub movements_spent: u32,
+}
+
+impl Board {
+    /// Checks if the action can be applied right now, without touching the board
+    pub fn check_action(&self, action: &Action) -> Result<(), RuleError> {
+        match action {
+            Action::PieceAction(action) => self.check_piece_action(action),
+            Action::CardAction(action) => self.check_card_action(action),
+            _ => Err(RuleError::NotExecutable),
+        }
+    }
+
+    /// Validates and executes the action, spending the costs and firing every hook
+    pub fn apply_action(&mut self, action: Action) -> Result<Outcome, RuleError> {
+        self.check_action(&action)?;
+        let mut outcome = Outcome::default();
+        match action {
+            Action::PieceAction(action) => self.execute_piece_action(action, &mut outcome)?,
+            Action::CardAction(action) => self.execute_card_action(action, &mut outcome),
+            _ => unreachable!("rejected by check_action"),
+        }
+        for _ in 0..outcome.movements_spent {
+            self.on_movement();
+        }
+        // the turn ends when the active player runs out of movements
+        if outcome.movements_spent > 0 && self.get_current_player().is_some_and(|p| !p.can_move()) {
+            self.on_turn();
+        }
+        Ok(outcome)
+    }
+
+    /// Every action the player could apply right now, empty if it is not their turn
+    pub fn legal_actions(&self, player_id: u32) -> Vec<Action> {
+        let Some(player) = self.get_player(player_id) else {
+            return Vec::new();
+        };
+
+        let mut candidates = Vec::new();
+        for square in self.board.iter().flatten() {
+            let Some(piece) = square.piece.as_ref().filter(|p| p.color == player.color) else {
+                continue;
+            };
+            for basic in [BasicAction::Move, BasicAction::Take, BasicAction::Attack] {
+                let pattern = piece.get_pattern(&basic);
+                if pattern.is_null() {
+                    continue;
+                }
+                for to in self.get_positions_with_action(square.pos, pattern, basic.clone()) {
+                    let from_to = FromToAction {
+                        from: square.pos,
+                        to,
+                        piece_id: piece.id,
+                    };
+                    candidates.push(match basic {
+                        BasicAction::Move => PieceAction::Move(from_to),
+                        BasicAction::Take => PieceAction::Take(from_to),
+                        _ => PieceAction::Attack(from_to),
+                    });
+                }
+            }
+            for target in self.ability_targets(square.pos, piece) {
+                candidates.push(PieceAction::Ability(AbilityAction {
+                    piece_id: piece.id,
+                    target,
+                }));
+            }
+        }
+
+        let cards = player.current_hand.cards.iter().map(|card| {
+            Action::CardAction(CardAction::PlayCard(PlayCardAction {
+                player_id,
+                card_id: card.id,
+            }))
+        });
+
+        candidates
+            .into_iter()
+            .map(Action::PieceAction)
+            .chain(cards)
+            .filter(|action| self.check_action(action).is_ok())
+            .collect()
+    }
+
+    // validation stuff
+    fn check_turn(&self, player_id: u32) -> Result<&Player, RuleError> {
+        let player = self.get_current_player().ok_or(RuleError::NoPlayer)?;
+        if player.id == player_id {
+            Ok(player)
+        } else {
+            Err(RuleError::NotYourTurn)
+        }
+    }
+
+    fn check_acting_player(&self, color: Color) -> Result<&Player, RuleError> {
+        let player = self.get_player_of_color(color).ok_or(RuleError::NoPlayer)?;
+        self.check_turn(player.id)
+    }
+
+    fn check_piece_action(&self, action: &PieceAction) -> Result<(), RuleError> {
+        match action {
+            PieceAction::Move(action) => self.check_from_to(action, BasicAction::Move),
+            PieceAction::Attack(action) => self.check_from_to(action, BasicAction::Attack),
+            PieceAction::Take(action) => self.check_from_to(action, BasicAction::Take),
+            PieceAction::Ability(action) => self.check_ability(action),
+            PieceAction::Die(_) | PieceAction::Kill(_) | PieceAction::Transform(_) => {
+                Err(RuleError::NotExecutable)
+            }
+        }
+    }
+
+    fn check_from_to(&self, action: &FromToAction, basic: BasicAction) -> Result<(), RuleError> {
+        let piece = self
+            .get_square(action.from)
+            .and_then(|square| square.piece.as_ref())
+            .filter(|piece| piece.id == action.piece_id)
+            .ok_or(RuleError::NoPiece)?;
+        let player = self.check_acting_player(piece.color)?;
+        if !player.can_move() {
+            return Err(RuleError::NoMovements);
+        }
+        if piece.moved {
+            return Err(RuleError::AlreadyMoved);
+        }
+        if piece.casting().is_some() {
+            return Err(RuleError::Casting);
+        }
+        if !piece.can_do_action(&basic, self) {
+            return Err(RuleError::ActionVetoed);
+        }
+
+        let square = self.get_square(action.to).ok_or(RuleError::OutOfBoard)?;
+        match (&basic, &square.piece) {
+            (BasicAction::Move, Some(_)) => return Err(RuleError::Occupied),
+            (BasicAction::Move, None) => {}
+            (_, None) => return Err(RuleError::NoTarget),
+            (_, Some(target)) if target.color == piece.color => {
+                return Err(RuleError::FriendlyTarget);
+            }
+            (_, Some(target)) if !target.can_receive_action(&basic, self) => {
+                return Err(RuleError::ReceiveVetoed);
+            }
+            _ => {}
+        }
+
+        if piece
+            .get_pattern(&basic)
+            .matches_action(action.from, action.to, self, &basic)
+        {
+            Ok(())
+        } else {
+            Err(RuleError::OutOfPattern)
+        }
+    }
+
+    fn check_ability(&self, action: &AbilityAction) -> Result<(), RuleError> {
+        let (pos, piece) = self.find_piece(action.piece_id).ok_or(RuleError::NoPiece)?;
+        let player = self.check_acting_player(piece.color)?;
+        let data = &piece.ability_data;
+        if data.autocast {
+            return Err(RuleError::NotExecutable);
+        }
+        if !player.can_use_movements(data.movement_cost) {
+            return Err(RuleError::NoMovements);
+        }
+        if !player.can_use_mana(data.mana_cost) {
+            return Err(RuleError::NoMana);
+        }
+        if piece.moved {
+            return Err(RuleError::AlreadyMoved);
+        }
+        if piece.casting().is_some() {
+            return Err(RuleError::Casting);
+        }
+        if !piece.ability_ready(self) {
+            return Err(RuleError::OnCooldown);
+        }
+        if !piece.can_do_action(&BasicAction::Ability, self) {
+            return Err(RuleError::ActionVetoed);
+        }
+        if data.pattern.has_pattern() && !data.pattern.matches(pos, pos, self) {
+            return Err(RuleError::OutOfPattern);
+        }
+        self.check_ability_target(pos, piece, &action.target)
+    }
+
+    fn check_card_action(&self, action: &CardAction) -> Result<(), RuleError> {
+        match action {
+            // taking cards has no cost nor allowed decks yet, the Imp is the way to draw
+            CardAction::TakeCard(_) => return Err(RuleError::NotExecutable),
+            CardAction::PutOnBoard(action) | CardAction::PlayCard(action) => {
+                let player = self.check_turn(action.player_id)?;
+                let card = player
+                    .current_hand
+                    .get_card(action.card_id)
+                    .ok_or(RuleError::NoCard)?;
+                if !player.can_use_mana(card.mana_cost) {
+                    return Err(RuleError::NoMana);
+                }
+            }
+            CardAction::DiscardCard(action) => {
+                let player = self.check_turn(action.player_id)?;
+                if !player.current_hand.has_card(action.card_id) {
+                    return Err(RuleError::NoCard);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // execution stuff, everything here was already validated
+    fn execute_piece_action(
+        &mut self,
+        action: PieceAction,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        match &action {
+            PieceAction::Move(from_to)
+            | PieceAction::Attack(from_to)
+            | PieceAction::Take(from_to) => {
+                let from_to = from_to.clone();
+                self.execute_from_to(from_to, action, outcome);
+            }
+            PieceAction::Ability(ability) => {
+                let (_, piece) = self
+                    .find_piece(ability.piece_id)
+                    .expect("checked by check_ability");
+                let piece = piece.clone();
+                let data = &piece.ability_data;
+                self.spend(piece.color, data.movement_cost, data.mana_cost, outcome);
+                self.set_piece_moved(piece.id, true);
+                // an ability that fails to resolve refunded its mana, give the rest back
+                if let Err(err) = self.use_ability(piece.id, ability.target.clone(), outcome) {
+                    if let Some(player) = self.get_player_of_color_mut(piece.color) {
+                        player.movements += data.movement_cost;
+                    }
+                    self.set_piece_moved(piece.id, piece.moved);
+                    return Err(err);
+                }
+                piece.on_action_done(&action, self);
+            }
+            PieceAction::Die(_) | PieceAction::Kill(_) | PieceAction::Transform(_) => {
+                unreachable!("rejected by check_action")
+            }
+        }
+        Ok(())
+    }
+
+    fn execute_from_to(
+        &mut self,
+        from_to: FromToAction,
+        action: PieceAction,
+        outcome: &mut Outcome,
+    ) {
+        let FromToAction { from, to, .. } = from_to;
+        let piece = self
+            .get_square(from)
+            .and_then(|square| square.piece.clone())
+            .expect("checked by check_from_to");
+        self.spend(piece.color, 1, 0, outcome);
+        self.set_piece_moved(piece.id, true);
+
+        let target = match action {
+            PieceAction::Move(_) => None,
+            _ => self.get_square(to).and_then(|square| square.piece.clone()),
+        };
+
+        piece.on_action_done(&action, self);
+        let mut killed = true;
+        if let Some(target) = target {
+            target.on_action_received(&action, self);
+            let damage = match action {
+                PieceAction::Attack(_) => piece.attack_damage(),
+                _ => piece.take_damage(),
+            };
+            outcome.damaged.push((target.id, damage));
+            killed = self.damage_piece(target.id, damage, Some(piece.id));
+            if killed {
+                outcome.killed.push(target.id);
+            }
+        }
+
+        // a Take that fails to kill leaves the taker in place
+        if killed && !matches!(action, PieceAction::Attack(_)) && self.move_piece(from, to).is_ok()
+        {
+            outcome.moved.push(from_to);
+        }
+    }
+
+    fn execute_card_action(&mut self, action: CardAction, outcome: &mut Outcome) {
+        match action {
+            CardAction::TakeCard(_) => unreachable!("rejected by check_card_action"),
+            CardAction::PutOnBoard(action) => {
+                let card = self.play_from_hand(action.player_id, action.card_id, outcome);
+                self.cards_on_board.push(PlayedCard {
+                    card,
+                    player_id: action.player_id,
+                });
+            }
+            CardAction::PlayCard(action) => {
+                let card = self.play_from_hand(action.player_id, action.card_id, outcome);
+                if let Some(player) = self.get_player_mut(action.player_id) {
+                    player.discard_pile.add_card(card);
+                }
+            }
+            CardAction::DiscardCard(action) => {
+                if let Some(player) = self.get_player_mut(action.player_id)
+                    && let Some(card) = player.current_hand.take_card(action.card_id)
+                {
+                    player.discard_pile.add_card(card);
+                }
+            }
+        }
+    }
+
+    fn play_from_hand(&mut self, player_id: u32, card_id: u32, outcome: &mut Outcome) -> Card {
+        let player = self
+            .get_player_mut(player_id)
+            .expect("checked by check_card_action");
+        let card = player
+            .current_hand
+            .take_card(card_id)
+            .expect("checked by check_card_action");
+        player.use_mana(card.mana_cost);
+        outcome.mana_spent += card.mana_cost;
+        card
+    }
+
+    fn spend(&mut self, color: Color, movements: u32, mana: u32, outcome: &mut Outcome) {
+        if let Some(player) = self.get_player_of_color_mut(color) {
+            player.use_movements(movements);
+            player.use_mana(mana);
+        }
+        outcome.movements_spent += movements;
+        outcome.mana_spent += mana;
+    }
+}
@@ -0,0 +1,35 @@
+use crate::prelude::*;
+
+impl Board {
+    // Spider
+    pub(crate) fn check_lay_egg(
+        &self,
+        pos: Position,
+        direction: Direction,
+    ) -> Result<(), RuleError> {
+        // eggs only go above or below the spider
+        if direction.axis() != Axis::Y {
+            return Err(RuleError::InvalidTarget);
+        }
+        match self.get_square(pos + direction) {
+            None => Err(RuleError::OutOfBoard),
+            Some(square) if square.piece.is_some() => Err(RuleError::Occupied),
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// Lays an egg of the spider color, it hatches on its own after its cast time.
+    /// Killing the egg before that kills its cast with it.
+    pub(crate) fn lay_egg(
+        &mut self,
+        pos: Position,
+        spider: &Piece,
+        direction: Direction,
+        outcome: &mut Outcome,
+    ) -> Result<(), RuleError> {
+        self.check_lay_egg(pos, direction)?;
+        let id = self.spawn_piece(pos + direction, spider.color, PieceType::SpiderEgg)?;
+        outcome.spawned.push(id);
+        Ok(())
+    }
+}
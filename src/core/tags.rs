@@ -0,0 +1,53 @@
+use crate::prelude::*;
+
+impl Board {
+    /// Squares on the left and right of the piece, relative to the forward of its color
+    pub fn sides_of(&self, pos: Position, piece: &Piece) -> [Position; 2] {
+        let side = self.forward(piece.color).axis().other();
+        [pos + side.to_direction(-1), pos + side.to_direction(1)]
+    }
+
+    pub(crate) fn grant_tag(&mut self, id: u32, tag: Tag, outcome: &mut Outcome) {
+        if self.add_tag(id, tag) {
+            outcome.tagged.push((id, tag));
+        }
+    }
+
+    // ShieldBearer
+    /// The pieces on the left and right become IMP for the rest of the game
+    pub(crate) fn shield_sides(
+        &mut self,
+        pos: Position,
+        shield_bearer: &Piece,
+        outcome: &mut Outcome,
+    ) {
+        let targets: Vec<u32> = self
+            .sides_of(pos, shield_bearer)
+            .into_iter()
+            .filter_map(|side| self.get_square(side)?.piece.as_ref())
+            .filter(|piece| piece.can_receive_action(&BasicAction::Ability, self))
+            .map(|piece| piece.id)
+            .collect();
+        for id in targets {
+            self.grant_tag(id, Tag::Impenetrable, outcome);
+        }
+    }
+
+    // Ship
+    /// The blast of 1 damage around the ship, except above and below it
+    pub(crate) fn broadside(&mut self, pos: Position, ship: &Piece, outcome: &mut Outcome) {
+        let targets: Vec<u32> = self
+            .blast_targets(pos, ship)
+            .into_iter()
+            .filter(|(target, _)| target.x != pos.x)
+            .map(|(_, id)| id)
+            .collect();
+        self.damage_pieces(&targets, ship, 1, outcome);
+    }
+
+    // SuperPawn
+    pub(crate) fn fortify(&mut self, super_pawn: &Piece, outcome: &mut Outcome) {
+        self.grant_tag(super_pawn.id, Tag::Immune, outcome);
+        self.grant_tag(super_pawn.id, Tag::Impenetrable, outcome);
+    }
+}
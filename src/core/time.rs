@@ -1,6 +1,9 @@
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use crate::prelude::*;
+
+/// Ordered by round, then turn, then movement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ChessTime {
     pub round: u32,
     pub turn: u32,
@@ -22,6 +25,38 @@ impl ChessTime {
         }
     }
 
+    pub fn from_rounds(round: u32) -> Self {
+        Self {
+            round,
+            turn: 0,
+            movement: 0,
+        }
+    }
+
+    pub fn from_turns(turn: u32) -> Self {
+        Self {
+            round: 0,
+            turn,
+            movement: 0,
+        }
+    }
+
+    /// Same time some rounds later, it never overflows
+    pub fn after_rounds(&self, rounds: u32) -> Self {
+        Self {
+            round: self.round.saturating_add(rounds),
+            ..*self
+        }
+    }
+
+    /// Same time some rounds earlier, it stops at round zero
+    pub fn before_rounds(&self, rounds: u32) -> Self {
+        Self {
+            round: self.round.saturating_sub(rounds),
+            ..*self
+        }
+    }
+
     pub fn on_movement(&mut self) {
         self.movement += 1;
     }
@@ -36,6 +71,19 @@ impl ChessTime {
         self.turn = 0;
         self.movement = 0;
     }
+
+    /// Used for durations, the component of the elapsed unit goes down by one
+    pub fn count_down(&mut self, elapsed: &BoardAction) {
+        match elapsed {
+            BoardAction::Movement => self.movement = self.movement.saturating_sub(1),
+            BoardAction::Turn => self.turn = self.turn.saturating_sub(1),
+            BoardAction::Round => self.round = self.round.saturating_sub(1),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.round == 0 && self.turn == 0 && self.movement == 0
+    }
 }
 
 impl Div<u32> for ChessTime {
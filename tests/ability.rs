@@ -0,0 +1,51 @@
+mod common;
+
+use common::{pass_until_round, setup, spawn, use_ability};
+use cursor2::prelude::*;
+
+fn has_ability_action(board: &Board, player_id: u32, piece_id: u32) -> bool {
+    board.legal_actions(player_id).iter().any(|action| {
+        matches!(
+            action,
+            Action::PieceAction(PieceAction::Ability(ability)) if ability.piece_id == piece_id
+        )
+    })
+}
+
+#[test]
+fn pieces_without_a_resolver_get_no_ability_action() {
+    let mut board = setup();
+    let player_id = board.get_current_player().unwrap().id;
+    let magician = spawn(&mut board, 1, 1, Color::White, PieceType::Magician);
+    let archer = spawn(&mut board, 5, 1, Color::White, PieceType::Archer);
+    let witch = spawn(&mut board, 3, 5, Color::White, PieceType::Witch);
+
+    assert!(!has_ability_action(&board, player_id, magician));
+    assert!(!has_ability_action(&board, player_id, archer));
+    assert!(has_ability_action(&board, player_id, witch));
+
+    let mana = board.get_player(player_id).unwrap().mana;
+    let result = use_ability(&mut board, magician, AbilityTarget::None);
+    assert_eq!(result.unwrap_err(), RuleError::NotExecutable);
+    assert_eq!(board.get_player(player_id).unwrap().mana, mana);
+    assert!(!board.get_piece(magician).unwrap().moved);
+}
+
+#[test]
+fn resolved_casts_are_reported_once() {
+    let mut board = setup();
+    let ram = spawn(&mut board, 3, 0, Color::White, PieceType::Ram);
+
+    use_ability(&mut board, ram, AbilityTarget::Direction(Direction::Up)).unwrap();
+    assert!(board.take_cast_outcomes().is_empty());
+
+    let round = board.time.round;
+    pass_until_round(&mut board, round + 1);
+
+    let outcomes = board.take_cast_outcomes();
+    assert_eq!(outcomes.len(), 1);
+    let (piece_id, result) = &outcomes[0];
+    assert_eq!(*piece_id, ram);
+    assert_eq!(result.as_ref().unwrap().moved.len(), 7);
+    assert!(board.take_cast_outcomes().is_empty());
+}
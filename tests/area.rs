@@ -0,0 +1,103 @@
+mod common;
+
+use common::{pass_until_round, setup, spawn, use_ability};
+use cursor2::prelude::*;
+
+fn deactivated(board: &Board, piece_id: u32) -> bool {
+    board
+        .get_piece(piece_id)
+        .unwrap()
+        .effects
+        .iter()
+        .any(|e| e.effect == Effect::Deactivate)
+}
+
+#[test]
+fn tesla_tower_deactivates_every_structure_around() {
+    let mut board = setup();
+    let player_id = board.get_current_player().unwrap().id;
+    board.get_player_mut(player_id).unwrap().movements = 2;
+    let tesla = spawn(&mut board, 3, 3, Color::White, PieceType::TeslaTower);
+    let ally = spawn(&mut board, 4, 3, Color::White, PieceType::Ram);
+    let enemy = spawn(&mut board, 2, 3, Color::Black, PieceType::Ram);
+    let pawn = spawn(&mut board, 3, 4, Color::Black, PieceType::Pawn);
+
+    use_ability(&mut board, tesla, AbilityTarget::None).unwrap();
+    let round = board.time.round;
+    pass_until_round(&mut board, round + 1);
+
+    assert!(deactivated(&board, ally));
+    assert!(deactivated(&board, enemy));
+    assert!(!deactivated(&board, pawn));
+    assert!(!deactivated(&board, tesla));
+}
+
+#[test]
+fn basilisk_deactivates_allies_and_enemies() {
+    let mut board = setup();
+    let basilisk = spawn(&mut board, 3, 3, Color::White, PieceType::Basilisk);
+    let ally = spawn(&mut board, 4, 4, Color::White, PieceType::Pawn);
+    let enemy = spawn(&mut board, 2, 2, Color::Black, PieceType::Pawn);
+    let outside = spawn(&mut board, 3, 4, Color::Black, PieceType::Pawn);
+
+    let outcome = use_ability(&mut board, basilisk, AbilityTarget::None).unwrap();
+
+    assert_eq!(outcome.affected.len(), 2);
+    assert!(deactivated(&board, ally));
+    assert!(deactivated(&board, enemy));
+    assert!(!deactivated(&board, outside));
+}
+
+#[test]
+fn witch_blast_hits_every_unit_around_but_heroes() {
+    let mut board = setup();
+    let witch = spawn(&mut board, 3, 3, Color::White, PieceType::Witch);
+    let ally = spawn(&mut board, 3, 4, Color::White, PieceType::Pawn);
+    let enemy = spawn(&mut board, 2, 2, Color::Black, PieceType::Pawn);
+    let queen = spawn(&mut board, 4, 3, Color::Black, PieceType::Queen);
+    let far = spawn(&mut board, 3, 5, Color::Black, PieceType::Pawn);
+
+    use_ability(&mut board, witch, AbilityTarget::None).unwrap();
+    let round = board.time.round;
+    pass_until_round(&mut board, round + 2);
+
+    let outcomes = board.take_cast_outcomes();
+    let mut killed = outcomes[0].1.as_ref().unwrap().killed.clone();
+    killed.sort();
+    assert_eq!(killed, vec![ally, enemy]);
+    assert!(board.get_piece(queen).is_some());
+    assert!(board.get_piece(far).is_some());
+}
+
+#[test]
+fn succubus_deactivates_one_piece_of_either_side_at_any_range() {
+    let mut board = setup();
+    let succubus = spawn(&mut board, 0, 0, Color::White, PieceType::Succubus);
+    let ally = spawn(&mut board, 7, 6, Color::White, PieceType::Pawn);
+    let enemy = spawn(&mut board, 7, 7, Color::Black, PieceType::Pawn);
+
+    let target = AbilityTarget::Position(Position::new(7, 6));
+    let outcome = use_ability(&mut board, succubus, target).unwrap();
+
+    assert_eq!(outcome.affected, vec![(ally, Effect::Deactivate)]);
+    assert!(deactivated(&board, ally));
+    assert!(!deactivated(&board, enemy));
+}
+
+#[test]
+fn succubus_cannot_charm_immune_pieces_or_itself() {
+    let mut board = setup();
+    let succubus = spawn(&mut board, 0, 0, Color::White, PieceType::Succubus);
+    spawn(&mut board, 5, 5, Color::Black, PieceType::Paladin);
+
+    let paladin = AbilityTarget::Position(Position::new(5, 5));
+    let itself = AbilityTarget::Position(Position::new(0, 0));
+    assert_eq!(
+        use_ability(&mut board, succubus, paladin).unwrap_err(),
+        RuleError::ReceiveVetoed
+    );
+    assert_eq!(
+        use_ability(&mut board, succubus, itself).unwrap_err(),
+        RuleError::InvalidTarget
+    );
+}
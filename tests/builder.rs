@@ -0,0 +1,91 @@
+mod common;
+
+use common::{setup, spawn, use_ability};
+use cursor2::prelude::*;
+
+fn build(board: &mut Board, builder: u32, direction: Direction) -> Result<Outcome, RuleError> {
+    use_ability(board, builder, AbilityTarget::Direction(direction))
+}
+
+fn wall_at(board: &Board, x: i32, y: i32) -> bool {
+    board
+        .get_square(Position::new(x, y))
+        .and_then(|square| square.piece.as_ref())
+        .is_some_and(|piece| piece.piece_type == PieceType::Wall)
+}
+
+#[test]
+fn builder_puts_a_line_of_walls() {
+    let mut board = setup();
+    let builder = spawn(&mut board, 3, 3, Color::White, PieceType::Builder);
+
+    let outcome = build(&mut board, builder, Direction::Up).unwrap();
+
+    assert_eq!(outcome.spawned.len(), 3);
+    assert!(wall_at(&board, 3, 4) && wall_at(&board, 3, 5) && wall_at(&board, 3, 6));
+}
+
+#[test]
+fn the_line_stops_at_the_board_edge() {
+    let mut board = setup();
+    let builder = spawn(&mut board, 3, 6, Color::White, PieceType::Builder);
+
+    let outcome = build(&mut board, builder, Direction::Up).unwrap();
+
+    assert_eq!(outcome.spawned.len(), 1);
+    assert!(wall_at(&board, 3, 7));
+}
+
+#[test]
+fn building_out_of_the_board_is_rejected() {
+    let mut board = setup();
+    let builder = spawn(&mut board, 3, 7, Color::White, PieceType::Builder);
+
+    let result = build(&mut board, builder, Direction::Up);
+
+    assert_eq!(result.unwrap_err(), RuleError::OutOfBoard);
+}
+
+#[test]
+fn walls_skip_occupied_squares_and_go_on_beyond_them() {
+    let mut board = setup();
+    let builder = spawn(&mut board, 3, 3, Color::White, PieceType::Builder);
+    let pawn = spawn(&mut board, 3, 5, Color::Black, PieceType::Pawn);
+
+    let outcome = build(&mut board, builder, Direction::Up).unwrap();
+
+    assert_eq!(outcome.spawned.len(), 2);
+    assert!(wall_at(&board, 3, 4) && wall_at(&board, 3, 6));
+    assert_eq!(board.find_piece(pawn).unwrap().0, Position::new(3, 5));
+}
+
+#[test]
+fn a_fully_blocked_line_is_rejected() {
+    let mut board = setup();
+    let builder = spawn(&mut board, 3, 3, Color::White, PieceType::Builder);
+    for y in 4..7 {
+        spawn(&mut board, 3, y, Color::Black, PieceType::Pawn);
+    }
+
+    let result = build(&mut board, builder, Direction::Up);
+
+    assert_eq!(result.unwrap_err(), RuleError::Occupied);
+}
+
+#[test]
+fn a_wall_deconstructs_itself() {
+    let mut board = setup();
+    let wall = spawn(&mut board, 3, 3, Color::White, PieceType::Wall);
+
+    let outcome = use_ability(&mut board, wall, AbilityTarget::None).unwrap();
+
+    assert_eq!(outcome.killed, vec![wall]);
+    assert!(board.get_piece(wall).is_none());
+    assert!(
+        board
+            .get_square(Position::new(3, 3))
+            .unwrap()
+            .piece
+            .is_none()
+    );
+}
@@ -0,0 +1,26 @@
+mod common;
+
+use common::setup;
+use cursor2::prelude::*;
+
+#[test]
+fn cards_cannot_be_taken_between_decks() {
+    let mut board = setup();
+    let player = board.get_current_player_mut().unwrap();
+    player.discard_pile.add_card(Card::new(7, 1));
+    let player_id = player.id;
+    let from_deck_id = player.discard_pile.id;
+    let to_deck_id = player.current_hand.id;
+
+    let result = board.apply_action(Action::CardAction(CardAction::TakeCard(TakeCardAction {
+        player_id,
+        card_id: 7,
+        from_deck_id,
+        to_deck_id,
+    })));
+
+    assert_eq!(result.unwrap_err(), RuleError::NotExecutable);
+    let player = board.get_player(player_id).unwrap();
+    assert!(player.discard_pile.has_card(7));
+    assert!(player.current_hand.cards.is_empty());
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use common::{setup, spawn, use_ability};
+use cursor2::prelude::*;
+
+/// White catapult at (3,3) with a white pawn below it
+fn setup_catapult() -> (Board, u32, u32) {
+    let mut board = setup();
+    let catapult = spawn(&mut board, 3, 3, Color::White, PieceType::Catapult);
+    let pawn = spawn(&mut board, 3, 2, Color::White, PieceType::Pawn);
+    (board, catapult, pawn)
+}
+
+fn throw(
+    board: &mut Board,
+    catapult: u32,
+    piece_id: u32,
+    distance: u32,
+) -> Result<Outcome, RuleError> {
+    use_ability(
+        board,
+        catapult,
+        AbilityTarget::Throw(ThrowAction {
+            piece_id,
+            direction: Direction::Up,
+            distance,
+        }),
+    )
+}
+
+#[test]
+fn thrown_piece_lands_on_an_empty_square() {
+    let (mut board, catapult, pawn) = setup_catapult();
+
+    let outcome = throw(&mut board, catapult, pawn, 3).unwrap();
+
+    assert_eq!(board.find_piece(pawn).unwrap().0, Position::new(3, 6));
+    assert_eq!(outcome.moved.len(), 1);
+}
+
+#[test]
+fn thrown_piece_dies_out_of_the_board() {
+    let (mut board, catapult, pawn) = setup_catapult();
+
+    let outcome = throw(&mut board, catapult, pawn, 5).unwrap();
+
+    assert_eq!(outcome.killed, vec![pawn]);
+    assert!(board.get_dead_piece(pawn).is_some());
+}
+
+#[test]
+fn thrown_piece_dies_on_imp_or_immune_units() {
+    for piece_type in [PieceType::Wall, PieceType::Paladin] {
+        let (mut board, catapult, pawn) = setup_catapult();
+        let target = spawn(&mut board, 3, 6, Color::Black, piece_type);
+
+        let outcome = throw(&mut board, catapult, pawn, 3).unwrap();
+
+        assert_eq!(outcome.killed, vec![pawn]);
+        assert_eq!(board.find_piece(target).unwrap().0, Position::new(3, 6));
+    }
+}
+
+#[test]
+fn thrown_piece_kills_the_unit_it_lands_on() {
+    let (mut board, catapult, pawn) = setup_catapult();
+    let enemy = spawn(&mut board, 3, 6, Color::Black, PieceType::Pawn);
+
+    let outcome = throw(&mut board, catapult, pawn, 3).unwrap();
+
+    assert_eq!(outcome.killed, vec![enemy]);
+    assert_eq!(board.find_piece(pawn).unwrap().0, Position::new(3, 6));
+}
+
+#[test]
+fn only_adjacent_transportable_pieces_can_be_thrown() {
+    let (mut board, catapult, _) = setup_catapult();
+    let far = spawn(&mut board, 3, 1, Color::White, PieceType::Pawn);
+    let rook = spawn(&mut board, 4, 3, Color::White, PieceType::Rook);
+
+    for piece_id in [far, rook] {
+        let result = throw(&mut board, catapult, piece_id, 3);
+        assert_eq!(result.unwrap_err(), RuleError::InvalidTarget);
+    }
+    assert_eq!(board.find_piece(far).unwrap().0, Position::new(3, 1));
+    assert_eq!(board.find_piece(rook).unwrap().0, Position::new(4, 3));
+}
@@ -0,0 +1,50 @@
+#![allow(dead_code)] // every test file uses a different part of the fixture
+
+use cursor2::prelude::*;
+
+/// 8x8 board with a player of each color, added in that order
+pub fn board_with(colors: &[Color]) -> Board {
+    let mut board = Board::new(8, 8);
+    for color in colors {
+        let id = board.generate_player_id();
+        let mut player = Player::new(id, [0, 1, 2, 3]);
+        player.color = *color;
+        board.add_player(player);
+    }
+    board
+}
+
+/// 8x8 board with White and Black, White plays first
+pub fn setup() -> Board {
+    board_with(&[Color::White, Color::Black])
+}
+
+pub fn spawn(board: &mut Board, x: i32, y: i32, color: Color, piece_type: PieceType) -> u32 {
+    board
+        .spawn_piece(Position::new(x, y), color, piece_type)
+        .unwrap()
+}
+
+pub fn use_ability(
+    board: &mut Board,
+    piece_id: u32,
+    target: AbilityTarget,
+) -> Result<Outcome, RuleError> {
+    board.apply_action(Action::PieceAction(PieceAction::Ability(AbilityAction {
+        piece_id,
+        target,
+    })))
+}
+
+/// The current player ends their turn
+pub fn pass(board: &mut Board) {
+    let player_id = board.get_current_player().unwrap().id;
+    board.pass_turn(player_id).unwrap();
+}
+
+/// Passes turns until the round is reached
+pub fn pass_until_round(board: &mut Board, round: u32) {
+    while board.time.round < round {
+        pass(board);
+    }
+}
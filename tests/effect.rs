@@ -0,0 +1,105 @@
+mod common;
+
+use common::{pass, pass_until_round, setup, spawn};
+use cursor2::prelude::*;
+
+fn duration_of(board: &Board, piece_id: u32, effect: Effect) -> Option<ChessTime> {
+    board
+        .get_piece(piece_id)?
+        .effects
+        .iter()
+        .find(|e| e.effect == effect)
+        .map(|e| e.duration)
+}
+
+fn move_pawn(board: &mut Board, piece_id: u32) -> Result<Outcome, RuleError> {
+    board.apply_action(Action::PieceAction(PieceAction::Move(FromToAction {
+        from: Position::new(3, 1),
+        to: Position::new(3, 2),
+        piece_id,
+    })))
+}
+
+#[test]
+fn fire_kills_the_piece_when_it_expires() {
+    let mut board = setup();
+    let pawn = spawn(&mut board, 3, 3, Color::Black, PieceType::Pawn);
+    board.add_effect(pawn, Effect::Fire, ChessTime::from_rounds(2));
+
+    pass_until_round(&mut board, 1);
+    assert_eq!(
+        duration_of(&board, pawn, Effect::Fire),
+        Some(ChessTime::from_rounds(1))
+    );
+
+    pass_until_round(&mut board, 2);
+    assert!(board.get_piece(pawn).is_none());
+}
+
+#[test]
+fn ice_and_deactivate_lock_the_piece_at_turn_start() {
+    for effect in [Effect::Ice, Effect::Deactivate] {
+        let mut board = setup();
+        let pawn = spawn(&mut board, 3, 1, Color::White, PieceType::Pawn);
+        board.add_effect(pawn, effect.clone(), ChessTime::from_rounds(4));
+
+        pass(&mut board);
+        pass(&mut board);
+
+        assert!(board.get_piece(pawn).unwrap().moved);
+        assert_eq!(
+            move_pawn(&mut board, pawn).unwrap_err(),
+            RuleError::AlreadyMoved
+        );
+    }
+}
+
+#[test]
+fn the_piece_is_free_again_once_the_lock_expires() {
+    let mut board = setup();
+    let pawn = spawn(&mut board, 3, 1, Color::White, PieceType::Pawn);
+    board.add_effect(pawn, Effect::Ice, ChessTime::from_rounds(1));
+
+    pass_until_round(&mut board, 1);
+
+    assert_eq!(duration_of(&board, pawn, Effect::Ice), None);
+    assert!(move_pawn(&mut board, pawn).is_ok());
+}
+
+#[test]
+fn fire_and_ice_last_half_as_long_on_str_pieces() {
+    for effect in [Effect::Fire, Effect::Ice] {
+        let mut board = setup();
+        let rook = spawn(&mut board, 0, 0, Color::White, PieceType::Rook);
+        let pawn = spawn(&mut board, 3, 3, Color::White, PieceType::Pawn);
+        board.add_effect(rook, effect.clone(), ChessTime::from_rounds(4));
+        board.add_effect(pawn, effect.clone(), ChessTime::from_rounds(4));
+
+        assert_eq!(
+            duration_of(&board, rook, effect.clone()),
+            Some(ChessTime::from_rounds(2))
+        );
+        assert_eq!(
+            duration_of(&board, pawn, effect),
+            Some(ChessTime::from_rounds(4))
+        );
+    }
+}
+
+#[test]
+fn deactivate_lasts_half_as_long_on_units() {
+    let mut board = setup();
+    let rook = spawn(&mut board, 0, 0, Color::White, PieceType::Rook);
+    let pawn = spawn(&mut board, 3, 3, Color::White, PieceType::Pawn);
+    board.add_effect(rook, Effect::Deactivate, ChessTime::from_rounds(4));
+    board.add_effect(pawn, Effect::Deactivate, ChessTime::from_rounds(4));
+
+    assert_eq!(
+        duration_of(&board, rook, Effect::Deactivate),
+        Some(ChessTime::from_rounds(4))
+    );
+    assert_eq!(
+        duration_of(&board, pawn, Effect::Deactivate),
+        Some(ChessTime::from_rounds(2))
+    );
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::{board_with, spawn};
+use cursor2::prelude::*;
+
+#[test]
+fn forward_follows_the_color_whatever_the_player_order() {
+    let board = board_with(&[Color::Black, Color::White]);
+
+    assert_eq!(board.forward(Color::White), Direction::Up);
+    assert_eq!(board.forward(Color::Black), Direction::Down);
+    assert_eq!(board.get_current_player().unwrap().color, Color::White);
+}
+
+#[test]
+fn pawns_move_forward_when_players_are_added_in_reverse_order() {
+    let mut board = board_with(&[Color::Black, Color::White]);
+    let pawn = spawn(&mut board, 3, 1, Color::White, PieceType::Pawn);
+
+    let moves = board.get_positions_with_action(
+        Position::new(3, 1),
+        board
+            .get_piece(pawn)
+            .unwrap()
+            .get_pattern(&BasicAction::Move),
+        BasicAction::Move,
+    );
+
+    assert!(moves.contains(&Position::new(3, 2)));
+    assert!(!moves.contains(&Position::new(3, 0)));
+}
+
+#[test]
+fn a_player_can_face_another_direction() {
+    let mut board = Board::new(8, 8);
+    let id = board.generate_player_id();
+    let mut player = Player::new(id, [0, 1, 2, 3]);
+    player.color = Color::White;
+    player.forward = Some(Direction::Right);
+    board.add_player(player);
+
+    assert_eq!(board.forward(Color::White), Direction::Right);
+}
+
+#[test]
+fn board_directions_follow_the_turn_order_of_the_colors() {
+    let mut board = board_with(&[Color::Black, Color::White]);
+    board.directions = vec![Direction::Right, Direction::Left];
+
+    assert_eq!(board.forward(Color::White), Direction::Right);
+    assert_eq!(board.forward(Color::Black), Direction::Left);
+
+    // the forward of the player wins over the board
+    let black = board.get_player_of_color(Color::Black).unwrap().id;
+    board.get_player_mut(black).unwrap().forward = Some(Direction::Up);
+    assert_eq!(board.forward(Color::Black), Direction::Up);
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use common::{pass, setup, spawn, use_ability};
+use cursor2::prelude::*;
+
+fn white(board: &Board) -> &Player {
+    board.get_player_of_color(Color::White).unwrap()
+}
+
+/// White has a mandragora and a second movement, so the turn goes on after the ability
+fn setup_mandragora() -> (Board, u32) {
+    let mut board = setup();
+    let player_id = board.get_current_player().unwrap().id;
+    board.get_player_mut(player_id).unwrap().movements = 2;
+    let mandragora = spawn(&mut board, 3, 3, Color::White, PieceType::Mandragora);
+    (board, mandragora)
+}
+
+#[test]
+fn the_extra_mana_can_be_spent_this_turn() {
+    let (mut board, mandragora) = setup_mandragora();
+    let mana = white(&board).mana;
+    let max_mana = white(&board).max_mana;
+
+    use_ability(&mut board, mandragora, AbilityTarget::None).unwrap();
+
+    assert_eq!(white(&board).max_mana, max_mana + 1);
+    assert_eq!(white(&board).mana, mana + 1);
+    assert_eq!(board.get_current_player().unwrap().color, Color::White);
+    assert!(white(&board).can_use_mana(mana + 1));
+}
+
+#[test]
+fn the_extra_max_mana_expires_after_the_next_turn() {
+    let (mut board, mandragora) = setup_mandragora();
+    let max_mana = white(&board).max_mana;
+
+    use_ability(&mut board, mandragora, AbilityTarget::None).unwrap();
+    // the next turn of White still has it
+    pass(&mut board);
+    pass(&mut board);
+    assert_eq!(board.get_current_player().unwrap().color, Color::White);
+    assert_eq!(white(&board).max_mana, max_mana + 1);
+
+    pass(&mut board);
+    assert_eq!(white(&board).max_mana, max_mana);
+    assert!(white(&board).mana <= max_mana);
+}
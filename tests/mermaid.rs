@@ -0,0 +1,113 @@
+mod common;
+
+use common::{pass, pass_until_round, setup, spawn, use_ability};
+use cursor2::prelude::*;
+
+/// Rounds left before each pending event of the piece
+fn pending_rounds(board: &Board, piece_id: u32) -> Vec<u32> {
+    board
+        .events_of(piece_id)
+        .iter()
+        .map(|event| event.when.round)
+        .collect()
+}
+
+fn schedule_mana(board: &mut Board, piece_id: u32, rounds: u32) {
+    let player_id = board.get_current_player().unwrap().id;
+    board.schedule_piece_event(
+        piece_id,
+        player_id,
+        ChessTime::from_rounds(rounds),
+        EventFunctionEnum::AddMana,
+    );
+}
+
+#[test]
+fn the_mermaid_brings_a_pending_summon_closer() {
+    let mut board = setup();
+    board.get_current_player_mut().unwrap().movements = 2;
+    let portal = spawn(&mut board, 3, 3, Color::White, PieceType::Portal);
+    let mermaid = spawn(&mut board, 5, 4, Color::White, PieceType::Mermaid);
+
+    let summon = AbilityTarget::Summon(PieceType::Golem, SubDirection::Up);
+    use_ability(&mut board, portal, summon).unwrap();
+    assert_eq!(pending_rounds(&board, portal), vec![5]);
+
+    use_ability(&mut board, mermaid, AbilityTarget::None).unwrap();
+    assert_eq!(pending_rounds(&board, portal), vec![3]);
+
+    pass_until_round(&mut board, 3);
+    let golem = board
+        .get_square(Position::new(3, 4))
+        .unwrap()
+        .piece
+        .as_ref();
+    assert_eq!(golem.map(|piece| piece.piece_type), Some(PieceType::Golem));
+}
+
+#[test]
+fn the_mermaid_ignores_enemy_and_far_portals() {
+    let mut board = setup();
+    let enemy_portal = spawn(&mut board, 4, 3, Color::Black, PieceType::Portal);
+    let far_portal = spawn(&mut board, 0, 7, Color::White, PieceType::Portal);
+    let mermaid = spawn(&mut board, 5, 4, Color::White, PieceType::Mermaid);
+    schedule_mana(&mut board, enemy_portal, 5);
+    schedule_mana(&mut board, far_portal, 5);
+
+    use_ability(&mut board, mermaid, AbilityTarget::None).unwrap();
+
+    assert_eq!(pending_rounds(&board, enemy_portal), vec![5]);
+    assert_eq!(pending_rounds(&board, far_portal), vec![5]);
+}
+
+#[test]
+fn events_of_a_piece_can_be_hastened_and_delayed() {
+    let mut board = setup();
+    let pawn = spawn(&mut board, 3, 3, Color::White, PieceType::Pawn);
+    let other = spawn(&mut board, 4, 4, Color::White, PieceType::Pawn);
+    schedule_mana(&mut board, pawn, 5);
+    schedule_mana(&mut board, pawn, 6);
+    schedule_mana(&mut board, other, 5);
+
+    board.hasten_events_of(pawn, 2);
+    assert_eq!(pending_rounds(&board, pawn), vec![3, 4]);
+    board.delay_events_of(pawn, 4);
+    assert_eq!(pending_rounds(&board, pawn), vec![7, 8]);
+    assert_eq!(pending_rounds(&board, other), vec![5]);
+
+    // hastening past due runs the events on the next count down
+    board.hasten_events_of(pawn, 10);
+    assert_eq!(pending_rounds(&board, pawn), vec![0, 0]);
+    pass(&mut board);
+    assert!(board.events_of(pawn).is_empty());
+}
+
+#[test]
+fn casts_can_be_hastened_and_delayed() {
+    let mut board = setup();
+    board.get_current_player_mut().unwrap().mana = 3;
+    board.get_square_mut(Position::new(3, 4)).unwrap().magic = true;
+    let warlock = spawn(&mut board, 3, 3, Color::White, PieceType::Warlock);
+    use_ability(
+        &mut board,
+        warlock,
+        AbilityTarget::SubDirection(SubDirection::Up),
+    )
+    .unwrap();
+    let ready_at = |board: &Board| {
+        board
+            .get_piece(warlock)
+            .unwrap()
+            .cast
+            .as_ref()
+            .unwrap()
+            .ready_at
+    };
+    let started = ready_at(&board);
+
+    board.hasten_cast(warlock, 2);
+    assert_eq!(ready_at(&board), started.before_rounds(2));
+    board.delay_cast(warlock, 1);
+    assert_eq!(ready_at(&board), started.before_rounds(1));
+    assert_eq!(board.pending_casts().len(), 1);
+}
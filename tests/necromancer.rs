@@ -0,0 +1,57 @@
+mod common;
+
+use common::{setup, spawn, use_ability};
+use cursor2::prelude::*;
+
+/// White necromancer at (3,3) that raised the black unit at (5,5)
+fn raise(piece_type: PieceType) -> (Board, u32, u32) {
+    let mut board = setup();
+    let player_id = board.get_current_player().unwrap().id;
+    board.get_player_mut(player_id).unwrap().movements = 2;
+    let necromancer = spawn(&mut board, 3, 3, Color::White, PieceType::Necromancer);
+    let enemy = spawn(&mut board, 5, 5, Color::Black, piece_type);
+
+    let target = AbilityTarget::Position(Position::new(5, 5));
+    let outcome = use_ability(&mut board, necromancer, target).unwrap();
+    assert_eq!(outcome.spawned, vec![enemy]);
+    (board, necromancer, enemy)
+}
+
+fn mana(board: &Board, color: Color) -> u32 {
+    board.get_player_of_color(color).unwrap().mana
+}
+
+#[test]
+fn raised_units_change_side_and_are_dead() {
+    let (board, _, raised) = raise(PieceType::Knight);
+
+    let piece = board.get_piece(raised).unwrap();
+    assert_eq!(piece.color, Color::White);
+    assert_eq!(piece.tags, vec![Tag::Dead]);
+    assert!(board.get_dead_piece(raised).is_none());
+}
+
+#[test]
+fn raised_units_die_with_their_necromancer() {
+    let (mut board, necromancer, raised) = raise(PieceType::Knight);
+
+    board.kill_piece(necromancer);
+
+    assert!(board.get_piece(raised).is_none());
+    assert!(board.get_dead_piece(raised).is_some());
+}
+
+#[test]
+fn dead_units_skip_their_death_hooks() {
+    let (mut board, _, raised) = raise(PieceType::Imp);
+    // even a DEM unit gives no mana when it is DEAD
+    board.add_tag(raised, Tag::Demonic);
+    let white = mana(&board, Color::White);
+    let black = mana(&board, Color::Black);
+
+    board.kill_piece(raised);
+
+    assert!(board.get_dead_piece(raised).is_some());
+    assert_eq!(mana(&board, Color::White), white);
+    assert_eq!(mana(&board, Color::Black), black);
+}
@@ -0,0 +1,125 @@
+use cursor2::prelude::*;
+
+fn setup() -> (Board, u32) {
+    let mut board = Board::new(8, 8);
+    for color in [Color::White, Color::Black] {
+        let id = board.generate_player_id();
+        let mut player = Player::new(id, [0, 1, 2, 3]);
+        player.color = color;
+        board.add_player(player);
+    }
+    let ogre = board
+        .spawn_piece(Position::new(3, 3), Color::White, PieceType::Ogre)
+        .unwrap();
+    (board, ogre)
+}
+
+fn push(
+    board: &mut Board,
+    ogre: u32,
+    piece_id: u32,
+    subdirection: SubDirection,
+) -> Result<Outcome, RuleError> {
+    board.apply_action(Action::PieceAction(PieceAction::Ability(AbilityAction {
+        piece_id: ogre,
+        target: AbilityTarget::Push(PushAction {
+            piece_id,
+            subdirection,
+        }),
+    })))
+}
+
+#[test]
+fn push_to_empty_square_moves_the_unit() {
+    let (mut board, ogre) = setup();
+    let pawn = board
+        .spawn_piece(Position::new(3, 4), Color::Black, PieceType::Pawn)
+        .unwrap();
+
+    let outcome = push(&mut board, ogre, pawn, SubDirection::UpRight).unwrap();
+
+    assert_eq!(board.find_piece(pawn).unwrap().0, Position::new(4, 5));
+    assert_eq!(outcome.moved.len(), 1);
+    assert!(outcome.killed.is_empty());
+}
+
+#[test]
+fn pushing_an_ally_kills_the_unit_it_lands_on() {
+    let (mut board, ogre) = setup();
+    let ally = board
+        .spawn_piece(Position::new(4, 3), Color::White, PieceType::Pawn)
+        .unwrap();
+    let enemy = board
+        .spawn_piece(Position::new(5, 3), Color::Black, PieceType::Pawn)
+        .unwrap();
+
+    let outcome = push(&mut board, ogre, ally, SubDirection::Right).unwrap();
+
+    assert_eq!(outcome.killed, vec![enemy]);
+    assert!(board.get_piece(enemy).is_none());
+    assert!(board.get_dead_piece(enemy).is_some());
+    assert_eq!(board.find_piece(ally).unwrap().0, Position::new(5, 3));
+}
+
+#[test]
+fn landing_on_an_immune_unit_kills_the_pushed_unit() {
+    let (mut board, ogre) = setup();
+    let pawn = board
+        .spawn_piece(Position::new(3, 2), Color::Black, PieceType::Pawn)
+        .unwrap();
+    let paladin = board
+        .spawn_piece(Position::new(3, 1), Color::Black, PieceType::Paladin)
+        .unwrap();
+
+    let outcome = push(&mut board, ogre, pawn, SubDirection::Down).unwrap();
+
+    assert_eq!(outcome.killed, vec![pawn]);
+    assert!(board.get_piece(pawn).is_none());
+    assert_eq!(board.find_piece(paladin).unwrap().0, Position::new(3, 1));
+}
+
+#[test]
+fn immune_targets_cannot_be_pushed() {
+    let (mut board, ogre) = setup();
+    let paladin = board
+        .spawn_piece(Position::new(2, 3), Color::Black, PieceType::Paladin)
+        .unwrap();
+
+    let result = push(&mut board, ogre, paladin, SubDirection::Left);
+
+    assert_eq!(result.unwrap_err(), RuleError::ReceiveVetoed);
+    assert_eq!(board.find_piece(paladin).unwrap().0, Position::new(2, 3));
+}
+
+#[test]
+fn pushing_out_of_the_board_is_rejected() {
+    let (mut board, _) = setup();
+    let ogre = board
+        .spawn_piece(Position::new(1, 0), Color::White, PieceType::Ogre)
+        .unwrap();
+    let pawn = board
+        .spawn_piece(Position::new(0, 0), Color::Black, PieceType::Pawn)
+        .unwrap();
+
+    for subdirection in [
+        SubDirection::Left,
+        SubDirection::Down,
+        SubDirection::DownLeft,
+    ] {
+        let result = push(&mut board, ogre, pawn, subdirection);
+        assert_eq!(result.unwrap_err(), RuleError::OutOfBoard);
+    }
+    assert_eq!(board.find_piece(pawn).unwrap().0, Position::new(0, 0));
+}
+
+#[test]
+fn only_adjacent_units_can_be_pushed() {
+    let (mut board, ogre) = setup();
+    let pawn = board
+        .spawn_piece(Position::new(3, 5), Color::Black, PieceType::Pawn)
+        .unwrap();
+
+    let result = push(&mut board, ogre, pawn, SubDirection::Up);
+
+    assert_eq!(result.unwrap_err(), RuleError::InvalidTarget);
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use common::{setup, spawn};
+use cursor2::prelude::*;
+
+fn reach(board: &Board, piece_id: u32, action: BasicAction) -> Vec<Position> {
+    let (pos, piece) = board.find_piece(piece_id).unwrap();
+    board.get_positions_with_action(pos, piece.get_pattern(&action), action)
+}
+
+#[test]
+fn rook_stops_at_the_first_piece() {
+    let mut board = setup();
+    let rook = spawn(&mut board, 0, 0, Color::White, PieceType::Rook);
+    spawn(&mut board, 0, 3, Color::White, PieceType::Pawn);
+
+    let moves = reach(&board, rook, BasicAction::Move);
+
+    assert!(moves.contains(&Position::new(0, 2)));
+    assert!(!moves.contains(&Position::new(0, 3)));
+    assert!(!moves.contains(&Position::new(0, 4)));
+    assert!(moves.contains(&Position::new(7, 0)));
+}
+
+#[test]
+fn blocking_square_is_excluded_for_move_and_included_for_take() {
+    let mut board = setup();
+    let rook = spawn(&mut board, 0, 0, Color::White, PieceType::Rook);
+    spawn(&mut board, 0, 3, Color::Black, PieceType::Pawn);
+
+    let moves = reach(&board, rook, BasicAction::Move);
+    let takes = reach(&board, rook, BasicAction::Take);
+
+    assert!(!moves.contains(&Position::new(0, 3)));
+    assert!(takes.contains(&Position::new(0, 3)));
+    assert!(!takes.contains(&Position::new(0, 4)));
+}
+
+#[test]
+fn balista_attack_goes_through_pieces_but_not_imp_ones() {
+    let mut board = setup();
+    let balista = spawn(&mut board, 3, 0, Color::White, PieceType::Balista);
+    spawn(&mut board, 3, 2, Color::Black, PieceType::Pawn);
+    spawn(&mut board, 3, 4, Color::Black, PieceType::Pawn);
+    spawn(&mut board, 5, 0, Color::Black, PieceType::Wall);
+    spawn(&mut board, 6, 0, Color::Black, PieceType::Pawn);
+
+    let attacks = reach(&board, balista, BasicAction::Attack);
+
+    assert!(attacks.contains(&Position::new(3, 2)));
+    assert!(attacks.contains(&Position::new(3, 4)));
+    assert!(attacks.contains(&Position::new(5, 0)));
+    assert!(!attacks.contains(&Position::new(6, 0)));
+}
+
+#[test]
+fn magician_moves_through_pieces() {
+    let mut board = setup();
+    let magician = spawn(&mut board, 3, 3, Color::White, PieceType::Magician);
+    spawn(&mut board, 3, 4, Color::Black, PieceType::Wall);
+
+    let moves = reach(&board, magician, BasicAction::Move);
+
+    assert!(!moves.contains(&Position::new(3, 4)));
+    assert!(moves.contains(&Position::new(3, 5)));
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::{pass_until_round, setup, spawn, use_ability};
+use cursor2::prelude::*;
+
+/// Starts a portal cast from (3,3) to the magic square above, blocked or not, and waits for it
+fn build_portal(blocked: bool) -> (Board, u32) {
+    let mut board = setup();
+    let player_id = board.get_current_player().unwrap().id;
+    board.get_player_mut(player_id).unwrap().mana = 3;
+    board.get_square_mut(Position::new(3, 4)).unwrap().magic = true;
+    let warlock = spawn(&mut board, 3, 3, Color::White, PieceType::Warlock);
+
+    let target = AbilityTarget::SubDirection(SubDirection::Up);
+    use_ability(&mut board, warlock, target).unwrap();
+    if blocked {
+        spawn(&mut board, 3, 4, Color::Black, PieceType::Pawn);
+    }
+    let round = board.time.round;
+    pass_until_round(&mut board, round + 3);
+    (board, warlock)
+}
+
+fn white_mana(board: &Board) -> u32 {
+    board.get_player_of_color(Color::White).unwrap().mana
+}
+
+#[test]
+fn the_warlock_builds_a_portal_after_its_cast() {
+    let (mut board, warlock) = build_portal(false);
+
+    assert_eq!(
+        board
+            .find_pieces_of_type(Color::White, PieceType::Portal)
+            .len(),
+        1
+    );
+    assert!(!board.get_piece(warlock).unwrap().ability_ready(&board));
+    let outcomes = board.take_cast_outcomes();
+    assert_eq!(outcomes[0].1.as_ref().unwrap().spawned.len(), 1);
+}
+
+#[test]
+fn a_blocked_portal_fizzles_and_refunds_its_mana() {
+    let (built, _) = build_portal(false);
+    let (mut board, warlock) = build_portal(true);
+
+    assert!(
+        board
+            .find_pieces_of_type(Color::White, PieceType::Portal)
+            .is_empty()
+    );
+    // all 3 mana come back, a built portal only refunds 2
+    assert_eq!(white_mana(&board), white_mana(&built) + 1);
+    assert!(board.get_piece(warlock).unwrap().ability_ready(&board));
+    let outcomes = board.take_cast_outcomes();
+    assert_eq!(outcomes, vec![(warlock, Err(RuleError::Occupied))]);
+}
@@ -0,0 +1,20 @@
+mod common;
+
+use common::{setup, spawn, use_ability};
+use cursor2::prelude::*;
+
+#[test]
+fn promoted_piece_does_not_take_the_cooldown_of_its_new_type() {
+    let mut board = setup();
+    let pawn = spawn(&mut board, 3, 7, Color::White, PieceType::Pawn);
+    let now = board.time;
+
+    let outcome = use_ability(&mut board, pawn, AbilityTarget::Promote(PieceType::Knight)).unwrap();
+
+    assert_eq!(outcome.transformed, vec![(pawn, PieceType::Knight)]);
+    let knight = board.get_piece(pawn).unwrap();
+    assert_eq!(knight.piece_type, PieceType::Knight);
+    // the pawn ability has no cooldown, the knight one would be 10 rounds
+    assert_eq!(knight.ability_ready_at, now);
+    assert!(knight.ability_ready(&board));
+}
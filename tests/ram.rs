@@ -0,0 +1,178 @@
+mod common;
+
+use cursor2::prelude::*;
+
+fn setup() -> (Board, u32) {
+    let mut board = common::setup();
+    let ram = common::spawn(&mut board, 3, 0, Color::White, PieceType::Ram);
+    (board, ram)
+}
+
+fn step(piece_id: u32, from: (i32, i32), to: (i32, i32)) -> RamStep {
+    RamStep::Move(FromToAction {
+        from: Position::new(from.0, from.1),
+        to: Position::new(to.0, to.1),
+        piece_id,
+    })
+}
+
+#[test]
+fn charging_into_the_border_ends_the_charge() {
+    let (mut board, ram) = setup();
+
+    let trace = board.ram(ram, Direction::Up).unwrap();
+
+    assert_eq!(trace.len(), 8);
+    assert_eq!(trace[6], step(ram, (3, 6), (3, 7)));
+    assert_eq!(trace[7], RamStep::Border);
+    assert_eq!(board.find_piece(ram).unwrap().0, Position::new(3, 7));
+}
+
+#[test]
+fn a_unit_that_survives_stops_the_ram() {
+    let (mut board, ram) = setup();
+    let pawn = common::spawn(&mut board, 3, 2, Color::Black, PieceType::Pawn);
+    board.get_piece_mut(pawn).unwrap().set_current_life(2);
+
+    let trace = board.ram(ram, Direction::Up).unwrap();
+
+    assert_eq!(
+        trace,
+        vec![
+            step(ram, (3, 0), (3, 1)),
+            RamStep::Hit {
+                piece_id: pawn,
+                damage: 1,
+                killed: false,
+            },
+            RamStep::Stop,
+        ]
+    );
+    assert_eq!(board.get_piece(pawn).unwrap().current_life(), 1);
+    assert_eq!(board.find_piece(ram).unwrap().0, Position::new(3, 1));
+}
+
+#[test]
+fn the_ram_moves_in_when_the_unit_dies() {
+    let (mut board, ram) = setup();
+    let pawn = common::spawn(&mut board, 3, 1, Color::Black, PieceType::Pawn);
+
+    let trace = board.ram(ram, Direction::Up).unwrap();
+
+    assert_eq!(
+        trace,
+        vec![
+            RamStep::Hit {
+                piece_id: pawn,
+                damage: 1,
+                killed: true,
+            },
+            step(ram, (3, 0), (3, 1)),
+            RamStep::Stop,
+        ]
+    );
+    assert!(board.get_piece(pawn).is_none());
+}
+
+#[test]
+fn depth_is_spent_moving_into_empty_squares() {
+    let (mut board, ram) = setup();
+    let pawn = common::spawn(&mut board, 3, 6, Color::Black, PieceType::Pawn);
+
+    let trace = board.ram(ram, Direction::Up).unwrap();
+
+    // 5 squares traveled give a depth of 1
+    assert_eq!(
+        trace[5],
+        RamStep::Hit {
+            piece_id: pawn,
+            damage: 1,
+            killed: true,
+        }
+    );
+    assert_eq!(
+        trace[6..],
+        [
+            step(ram, (3, 5), (3, 6)),
+            step(ram, (3, 6), (3, 7)),
+            RamStep::Stop,
+        ]
+    );
+}
+
+#[test]
+fn depth_is_spent_ramming_the_units_behind() {
+    let (mut board, ram) = setup();
+    let front = common::spawn(&mut board, 3, 6, Color::Black, PieceType::Pawn);
+    let behind = common::spawn(&mut board, 3, 7, Color::Black, PieceType::Pawn);
+    board.get_piece_mut(behind).unwrap().set_current_life(2);
+
+    let trace = board.ram(ram, Direction::Up).unwrap();
+
+    assert_eq!(
+        trace[6..],
+        [
+            step(ram, (3, 5), (3, 6)),
+            RamStep::Hit {
+                piece_id: behind,
+                damage: 1,
+                killed: false,
+            },
+            RamStep::Stop,
+        ]
+    );
+    assert!(board.get_piece(front).is_none());
+    assert_eq!(board.get_piece(behind).unwrap().current_life(), 1);
+}
+
+#[test]
+fn ramming_an_imp_unit_damages_both() {
+    let (mut board, ram) = setup();
+    let ogre = common::spawn(&mut board, 3, 2, Color::Black, PieceType::Ogre);
+
+    let trace = board.ram(ram, Direction::Up).unwrap();
+
+    assert_eq!(
+        trace,
+        vec![
+            step(ram, (3, 0), (3, 1)),
+            RamStep::Impact {
+                piece_id: ogre,
+                killed: false,
+                ram_killed: true,
+            },
+        ]
+    );
+    assert_eq!(board.get_piece(ogre).unwrap().current_life(), 1);
+    assert!(board.get_piece(ram).is_none());
+}
+
+#[test]
+fn ramming_an_imp_unit_can_kill_it() {
+    let (mut board, ram) = setup();
+    let wall = common::spawn(&mut board, 3, 1, Color::Black, PieceType::Wall);
+
+    let trace = board.ram(ram, Direction::Up).unwrap();
+
+    assert_eq!(
+        trace,
+        vec![RamStep::Impact {
+            piece_id: wall,
+            killed: true,
+            ram_killed: true,
+        }]
+    );
+    assert!(board.get_piece(wall).is_none());
+}
+
+#[test]
+fn immune_units_block_the_charge() {
+    let (mut board, ram) = setup();
+    let paladin = common::spawn(&mut board, 3, 3, Color::Black, PieceType::Paladin);
+
+    let trace = board.ram(ram, Direction::Up).unwrap();
+
+    assert_eq!(trace.last(), Some(&RamStep::Blocked { piece_id: paladin }));
+    assert_eq!(board.find_piece(ram).unwrap().0, Position::new(3, 2));
+    assert!(board.get_piece(paladin).is_some());
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use std::collections::HashSet;
+
+use cursor2::prelude::*;
+
+fn rolls(rng: &mut ChessRandom) -> Vec<u64> {
+    (0..16)
+        .map(|i| match i % 3 {
+            0 => {
+                rng.on_movement();
+                rng.movement_rng
+            }
+            1 => {
+                rng.on_turn();
+                rng.turn_rng
+            }
+            _ => {
+                rng.on_round();
+                rng.round_rng
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn same_seed_gives_the_same_sequence() {
+    let first = rolls(&mut ChessRandom::new(42));
+    let second = rolls(&mut ChessRandom::new(42));
+    let other = rolls(&mut ChessRandom::new(43));
+
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+}
+
+#[test]
+fn same_seed_gives_the_same_board_rolls() {
+    let play = |seed: u64| {
+        let mut board = common::setup();
+        board.set_seed(seed);
+        let mut turn_rolls = Vec::new();
+        for _ in 0..6 {
+            common::pass(&mut board);
+            turn_rolls.push(board.rng.turn_rng);
+        }
+        turn_rolls
+    };
+
+    assert_eq!(play(7), play(7));
+    assert_ne!(play(7), play(8));
+}
+
+/// Squares the crazy pawn should reach: 1 or 2 steps in the rolled subdirection
+fn rolled_moves(board: &Board, pos: Position) -> HashSet<Position> {
+    let subdirection = ChessRandom::to_subdirection(board.rng.turn_rng);
+    [pos + subdirection, pos + subdirection + subdirection]
+        .into_iter()
+        .filter(|pos| board.get_square(*pos).is_some())
+        .collect()
+}
+
+fn crazy_pawn_moves(board: &Board, piece_id: u32) -> HashSet<Position> {
+    let (pos, piece) = board.find_piece(piece_id).unwrap();
+    let pattern = piece.get_pattern(&BasicAction::Move);
+    board
+        .get_positions_with_action(pos, pattern, BasicAction::Move)
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn crazy_pawn_moves_follow_the_rolled_subdirection() {
+    let mut board = common::setup();
+    board.set_seed(3);
+    let pos = Position::new(3, 3);
+    let crazy_pawn = common::spawn(&mut board, 3, 3, Color::White, PieceType::CrazyPawn);
+
+    let first = crazy_pawn_moves(&board, crazy_pawn);
+    assert_eq!(first, rolled_moves(&board, pos));
+
+    // a new turn rolls again, until it lands on another subdirection
+    let mut moves = first.clone();
+    for _ in 0..16 {
+        common::pass(&mut board);
+        moves = crazy_pawn_moves(&board, crazy_pawn);
+        assert_eq!(moves, rolled_moves(&board, pos));
+        if moves != first {
+            break;
+        }
+    }
+    assert_ne!(moves, first);
+}
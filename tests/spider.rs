@@ -0,0 +1,66 @@
+mod common;
+
+use common::{pass_until_round, setup, spawn, use_ability};
+use cursor2::prelude::*;
+
+#[test]
+fn hatched_spider_can_use_its_ability_right_away() {
+    let mut board = setup();
+    let spider = spawn(&mut board, 3, 3, Color::White, PieceType::Spider);
+
+    let outcome = use_ability(&mut board, spider, AbilityTarget::Direction(Direction::Up)).unwrap();
+    let egg = outcome.spawned[0];
+    assert_eq!(
+        board.get_piece(egg).unwrap().piece_type,
+        PieceType::SpiderEgg
+    );
+
+    pass_until_round(&mut board, 5);
+
+    let hatched = board.get_piece(egg).unwrap();
+    assert_eq!(hatched.piece_type, PieceType::Spider);
+    assert_eq!(hatched.color, Color::White);
+    assert!(hatched.ability_ready(&board));
+}
+
+#[test]
+fn killed_egg_never_hatches() {
+    let mut board = setup();
+    let spider = spawn(&mut board, 3, 3, Color::White, PieceType::Spider);
+
+    let outcome = use_ability(
+        &mut board,
+        spider,
+        AbilityTarget::Direction(Direction::Down),
+    )
+    .unwrap();
+    let egg = outcome.spawned[0];
+    board.kill_piece(egg);
+
+    pass_until_round(&mut board, 6);
+
+    assert!(board.get_piece(egg).is_none());
+    assert_eq!(
+        board.get_dead_piece(egg).unwrap().piece_type,
+        PieceType::SpiderEgg
+    );
+}
+
+#[test]
+fn raised_egg_still_hatches() {
+    let mut board = setup();
+    let player_id = board.get_current_player().unwrap().id;
+    board.get_player_mut(player_id).unwrap().movements = 2;
+    let necromancer = spawn(&mut board, 3, 3, Color::White, PieceType::Necromancer);
+    let egg = spawn(&mut board, 5, 5, Color::Black, PieceType::SpiderEgg);
+
+    let target = AbilityTarget::Position(Position::new(5, 5));
+    use_ability(&mut board, necromancer, target).unwrap();
+    assert!(board.get_piece(egg).unwrap().cast.is_some());
+
+    pass_until_round(&mut board, 6);
+
+    let hatched = board.get_piece(egg).unwrap();
+    assert_eq!(hatched.piece_type, PieceType::Spider);
+    assert_eq!(hatched.color, Color::White);
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use common::{pass, setup, spawn};
+use cursor2::prelude::*;
+
+fn ability(board: &mut Board, piece_id: u32) -> Outcome {
+    common::use_ability(board, piece_id, AbilityTarget::None).unwrap()
+}
+
+fn move_piece(board: &mut Board, piece_id: u32, to: Position) {
+    let (from, _) = board.find_piece(piece_id).unwrap();
+    board
+        .apply_action(Action::PieceAction(PieceAction::Move(FromToAction {
+            from,
+            to,
+            piece_id,
+        })))
+        .unwrap();
+}
+
+#[test]
+fn tags_can_be_added_and_removed_once() {
+    let mut board = setup();
+    let pawn = spawn(&mut board, 3, 3, Color::White, PieceType::Pawn);
+
+    assert!(board.add_tag(pawn, Tag::Immune));
+    assert!(!board.add_tag(pawn, Tag::Immune));
+    assert!(board.get_piece(pawn).unwrap().is_imm());
+
+    assert!(board.remove_tag(pawn, Tag::Immune));
+    assert!(!board.remove_tag(pawn, Tag::Immune));
+    assert!(!board.get_piece(pawn).unwrap().is_imm());
+}
+
+#[test]
+fn shield_bearer_makes_its_sides_impenetrable_for_good() {
+    let mut board = setup();
+    let shield_bearer = spawn(&mut board, 3, 1, Color::White, PieceType::ShieldBearer);
+    let left = spawn(&mut board, 2, 1, Color::White, PieceType::Pawn);
+    let right = spawn(&mut board, 4, 1, Color::White, PieceType::Pawn);
+    let front = spawn(&mut board, 3, 2, Color::White, PieceType::Pawn);
+
+    let outcome = ability(&mut board, shield_bearer);
+
+    assert_eq!(
+        outcome.tagged,
+        vec![(left, Tag::Impenetrable), (right, Tag::Impenetrable)]
+    );
+    assert!(!board.get_piece(front).unwrap().is_imp());
+
+    pass(&mut board);
+    move_piece(&mut board, left, Position::new(2, 2));
+
+    assert_eq!(board.find_piece(left).unwrap().0, Position::new(2, 2));
+    assert!(board.get_piece(left).unwrap().is_imp());
+    assert!(board.get_piece(right).unwrap().is_imp());
+}
+
+#[test]
+fn super_pawn_keeps_immune_and_impenetrable_after_moving() {
+    let mut board = setup();
+    let super_pawn = spawn(&mut board, 3, 1, Color::White, PieceType::SuperPawn);
+
+    let outcome = ability(&mut board, super_pawn);
+
+    assert_eq!(
+        outcome.tagged,
+        vec![(super_pawn, Tag::Immune), (super_pawn, Tag::Impenetrable)]
+    );
+
+    pass(&mut board);
+    move_piece(&mut board, super_pawn, Position::new(3, 2));
+
+    let piece = board.get_piece(super_pawn).unwrap();
+    assert!(piece.is_imm());
+    assert!(piece.is_imp());
+}
+
+#[test]
+fn ship_hits_every_side_but_up_and_down() {
+    let mut board = setup();
+    let ship = spawn(&mut board, 3, 3, Color::White, PieceType::Ship);
+    let up = spawn(&mut board, 3, 4, Color::Black, PieceType::Pawn);
+    let down = spawn(&mut board, 3, 2, Color::Black, PieceType::Pawn);
+    let mut hit = Vec::new();
+    for (x, y) in [(4, 4), (4, 3), (4, 2), (2, 2), (2, 3), (2, 4)] {
+        hit.push(spawn(&mut board, x, y, Color::Black, PieceType::Pawn));
+    }
+
+    let outcome = ability(&mut board, ship);
+
+    let mut killed = outcome.killed.clone();
+    killed.sort();
+    assert_eq!(killed, hit);
+    assert!(board.get_piece(up).is_some());
+    assert!(board.get_piece(down).is_some());
+}
+
+#[test]
+fn ship_hits_allies_but_not_heroes() {
+    let mut board = setup();
+    let ship = spawn(&mut board, 3, 3, Color::White, PieceType::Ship);
+    let ally = spawn(&mut board, 4, 3, Color::White, PieceType::Pawn);
+    let queen = spawn(&mut board, 2, 3, Color::Black, PieceType::Queen);
+
+    let outcome = ability(&mut board, ship);
+
+    assert_eq!(outcome.killed, vec![ally]);
+    assert!(board.get_piece(queen).is_some());
+}